@@ -0,0 +1,113 @@
+//! Build script support for the day crates. Each day lists its worked examples in an
+//! `examples.toml` next to its `Cargo.toml`:
+//!
+//! ```toml
+//! [[example]]
+//! file = "test"
+//! part-one = "7"
+//! part-two = "5"
+//!
+//! [[example]]
+//! input = "8A004A801A8002F478"
+//! part-one = "16"
+//! ```
+//!
+//! Every example needs exactly one of `file` or `input`, and either answer may be left out.
+//! `generate` turns the manifest into an `EXAMPLE_INPUTS` constant and one test per answer,
+//! written to `$OUT_DIR/examples.rs` for the day to `include!`.
+
+use serde::Deserialize;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    #[serde(rename = "example")]
+    examples: Vec<Example>
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct Example {
+    file: Option<String>,
+    input: Option<String>,
+    part_one: Option<String>,
+    part_two: Option<String>
+}
+
+/// Generates the examples of the crate being built, where `solution` names its `Solution`
+/// type, which the tests build with `Default`. Meant to be the whole of a day's `build.rs`.
+pub fn generate(solution: &str) {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo");
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    let manifest_path = Path::new(&manifest_dir).join("examples.toml");
+
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+
+    let contents = fs::read_to_string(&manifest_path)
+        .unwrap_or_else(|e| panic!("Could not read {}: {}", manifest_path.display(), e));
+    let source = render(Path::new(&manifest_dir), solution, &contents)
+        .unwrap_or_else(|e| panic!("{}: {}", manifest_path.display(), e));
+
+    fs::write(Path::new(&out_dir).join("examples.rs"), source).expect("Could not write examples.rs");
+}
+
+/// Builds the Rust source for a manifest, with `file` examples resolved against `dir`.
+pub fn render(dir: &Path, solution: &str, manifest: &str) -> Result<String, String> {
+    let manifest: Manifest = toml::from_str(manifest).map_err(|e| e.to_string())?;
+    let mut inputs = String::new();
+    let mut tests = String::new();
+
+    for (i, example) in manifest.examples.iter().enumerate() {
+        let number = i + 1;
+
+        match (&example.file, &example.input) {
+            (Some(file), None) => {
+                let path = dir.join(file);
+                println!("cargo:rerun-if-changed={}", path.display());
+                writeln!(inputs, "    include_str!({:?}),", path.display().to_string()).unwrap();
+            },
+            (None, Some(input)) => writeln!(inputs, "    {:?},", input).unwrap(),
+            _ => return Err(format!("example {} needs exactly one of `file` or `input`", number))
+        }
+
+        let answers = [("one", "One", &example.part_one), ("two", "Two", &example.part_two)];
+
+        for (name, part, expected) in answers {
+            if let Some(expected) = expected {
+                writeln!(
+                    tests,
+                    "\n    #[test]\n    fn example_{}_part_{}() {{\n        aoc_common::assert_example(&super::{}::default(), {}, aoc_common::Part::{}, {:?});\n    }}",
+                    number, name, solution, number, part, expected
+                ).unwrap();
+            }
+        }
+    }
+
+    // Days without parameters are unit structs, which clippy would rather not see defaulted
+    Ok(format!(
+        "// Generated by aoc-build from examples.toml.\n\nconst EXAMPLE_INPUTS: &[&str] = &[\n{}];\n\n#[cfg(test)]\n#[allow(clippy::default_constructed_unit_structs)]\nmod examples {{{}}}\n",
+        inputs, tests
+    ))
+}
+
+#[test]
+fn test_render() {
+    let manifest = "[[example]]\nfile = \"test\"\npart-two = \"5\"\n\n[[example]]\ninput = \"1,2\"\npart-one = \"3\"\n";
+    let source = render(Path::new("/days/day-1"), "Day1", manifest).unwrap();
+
+    assert!(source.contains("include_str!(\"/days/day-1/test\"),\n    \"1,2\",\n];"));
+    assert!(source.contains("fn example_1_part_two() {\n        aoc_common::assert_example(&super::Day1::default(), 1, aoc_common::Part::Two, \"5\");"));
+    assert!(source.contains("fn example_2_part_one()"));
+    assert!(!source.contains("fn example_1_part_one()"));
+}
+
+#[test]
+fn test_render_errors() {
+    assert!(render(Path::new("."), "Day1", "[[example]]\npart-one = \"3\"\n").is_err());
+    assert!(render(Path::new("."), "Day1", "[[example]]\ninput = \"1\"\nfile = \"test\"\n").is_err());
+    assert!(render(Path::new("."), "Day1", "[[example]]\ninput = \"1\"\npart-three = \"3\"\n").is_err());
+}
@@ -0,0 +1,156 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+pub use num_bigint::BigUint;
+
+/// The integer types a count can be kept in, chosen with a day's `integer` parameter.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Integer {
+    #[default]
+    U64,
+    U128,
+    Big
+}
+
+impl fmt::Display for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Integer::U64 => write!(f, "u64"),
+            Integer::U128 => write!(f, "u128"),
+            Integer::Big => write!(f, "big")
+        }
+    }
+}
+
+impl FromStr for Integer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "u64" => Ok(Integer::U64),
+            "u128" => Ok(Integer::U128),
+            "big" => Ok(Integer::Big),
+            _ => Err(format!("'{}' is not an integer type, expected u64, u128 or big", s))
+        }
+    }
+}
+
+/// Returned when a count grows past the largest value its integer type can hold.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overflow {
+    pub integer: Integer
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the count no longer fits in a {}, try a wider integer", self.integer)
+    }
+}
+
+impl Error for Overflow {}
+
+/// A number of things, like fish or universes, that only ever grows by adding and
+/// multiplying. Arithmetic reports overflow instead of wrapping, and `BigUint` never
+/// overflows at all.
+pub trait Count: Clone + Ord + fmt::Display {
+    const INTEGER: Integer;
+
+    fn from_u64(n: u64) -> Self;
+    fn add(&self, other: &Self) -> Result<Self, Overflow>;
+    fn mul(&self, other: &Self) -> Result<Self, Overflow>;
+
+    /// Subtracts `other`, which must be no larger than `self`.
+    fn sub(&self, other: &Self) -> Self;
+    fn div(&self, divisor: u64) -> Self;
+
+    fn zero() -> Self {
+        Self::from_u64(0)
+    }
+}
+
+impl Count for u64 {
+    const INTEGER: Integer = Integer::U64;
+
+    fn from_u64(n: u64) -> Self {
+        n
+    }
+
+    fn add(&self, other: &Self) -> Result<Self, Overflow> {
+        self.checked_add(*other).ok_or(Overflow { integer: Self::INTEGER })
+    }
+
+    fn mul(&self, other: &Self) -> Result<Self, Overflow> {
+        self.checked_mul(*other).ok_or(Overflow { integer: Self::INTEGER })
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self - other
+    }
+
+    fn div(&self, divisor: u64) -> Self {
+        self / divisor
+    }
+}
+
+impl Count for u128 {
+    const INTEGER: Integer = Integer::U128;
+
+    fn from_u64(n: u64) -> Self {
+        n as u128
+    }
+
+    fn add(&self, other: &Self) -> Result<Self, Overflow> {
+        self.checked_add(*other).ok_or(Overflow { integer: Self::INTEGER })
+    }
+
+    fn mul(&self, other: &Self) -> Result<Self, Overflow> {
+        self.checked_mul(*other).ok_or(Overflow { integer: Self::INTEGER })
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self - other
+    }
+
+    fn div(&self, divisor: u64) -> Self {
+        self / divisor as u128
+    }
+}
+
+impl Count for BigUint {
+    const INTEGER: Integer = Integer::Big;
+
+    fn from_u64(n: u64) -> Self {
+        BigUint::from(n)
+    }
+
+    fn add(&self, other: &Self) -> Result<Self, Overflow> {
+        Ok(self + other)
+    }
+
+    fn mul(&self, other: &Self) -> Result<Self, Overflow> {
+        Ok(self * other)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self - other
+    }
+
+    fn div(&self, divisor: u64) -> Self {
+        self / divisor
+    }
+}
+
+#[test]
+fn test_count() {
+    assert_eq!(Count::add(&u64::MAX, &1), Err(Overflow { integer: Integer::U64 }));
+    assert_eq!(Count::mul(&(u64::MAX as u128), &2), Ok(u64::MAX as u128 * 2));
+
+    let big = BigUint::from_u64(u64::MAX);
+    assert_eq!(Count::mul(&big, &big).unwrap().to_string(), "340282366920938463426481119284349108225");
+    assert_eq!(Count::sub(&big, &BigUint::from_u64(5)).div(10), BigUint::from_u64((u64::MAX - 5) / 10));
+
+    assert_eq!("big".parse(), Ok(Integer::Big));
+    assert!("i32".parse::<Integer>().is_err());
+    assert_eq!(Overflow { integer: Integer::U128 }.to_string(), "the count no longer fits in a u128, try a wider integer");
+}
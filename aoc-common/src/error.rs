@@ -0,0 +1,190 @@
+use crate::{Overflow, Part};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Returned when puzzle input does not match the format a day expects. Lines and columns
+/// are counted from 1, the same way editors show them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    file: Option<String>,
+    line: usize,
+    column: usize,
+    expected: String,
+    found: Option<String>
+}
+
+impl ParseError {
+    pub fn new<S: Into<String>>(line: usize, column: usize, expected: S) -> ParseError {
+        ParseError { file: None, line, column, expected: expected.into(), found: None }
+    }
+
+    /// Builds an error pointing at `token`, which must be a slice borrowed from `input`.
+    pub fn at<S: Into<String>>(input: &str, token: &str, expected: S) -> ParseError {
+        let (line, column) = position(input, token);
+        let found = match token.is_empty() {
+            true => "nothing".to_string(),
+            false => format!("\"{}\"", token)
+        };
+
+        ParseError::new(line, column, expected).found(found)
+    }
+
+    /// Builds an error for input that stopped before `expected` was read.
+    pub fn at_end<S: Into<String>>(input: &str, expected: S) -> ParseError {
+        let trimmed = input.trim_end_matches('\n');
+        let (line, column) = position(input, &trimmed[trimmed.len()..]);
+
+        ParseError::new(line, column, expected).found("end of input")
+    }
+
+    pub fn found<S: Into<String>>(mut self, found: S) -> ParseError {
+        self.found = Some(found.into());
+        self
+    }
+
+    pub fn with_file<S: Into<String>>(mut self, file: S) -> ParseError {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// Moves the error to `line`, for input read and parsed one line at a time.
+    pub fn on_line(mut self, line: usize) -> ParseError {
+        self.line = line;
+        self
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}: ", file, self.line, self.column)?,
+            None => write!(f, "line {}, column {}: ", self.line, self.column)?
+        }
+
+        write!(f, "expected {}", self.expected)?;
+
+        match &self.found {
+            Some(found) => write!(f, ", found {}", found),
+            None => Ok(())
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Returned when a solver can't answer: its input is malformed, one of its counts grew past
+/// the integer type it was kept in, or the input is well formed but has no answer, like a
+/// bingo game nobody wins.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    Overflow(Overflow),
+    NoAnswer(String),
+    /// One of the others, from solving the given part.
+    InPart(Part, Box<SolveError>)
+}
+
+impl SolveError {
+    pub fn no_answer<S: Into<String>>(reason: S) -> SolveError {
+        SolveError::NoAnswer(reason.into())
+    }
+
+    pub fn in_part(self, part: Part) -> SolveError {
+        SolveError::InPart(part, Box::new(self))
+    }
+
+    /// Names the file the input came from, for errors that point into it.
+    pub fn with_file<S: Into<String>>(self, file: S) -> SolveError {
+        match self {
+            SolveError::Parse(e) => SolveError::Parse(e.with_file(file)),
+            SolveError::InPart(part, e) => e.with_file(file).in_part(part),
+            other => other
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> SolveError {
+        SolveError::Parse(e)
+    }
+}
+
+impl From<Overflow> for SolveError {
+    fn from(overflow: Overflow) -> SolveError {
+        SolveError::Overflow(overflow)
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{}", e),
+            SolveError::Overflow(overflow) => write!(f, "{}", overflow),
+            SolveError::NoAnswer(reason) => write!(f, "{}", reason),
+            SolveError::InPart(part, e) => write!(f, "part {}: {}", part, e)
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// Parses `token`, a slice of `input`, reporting its position if it is not a valid `T`.
+pub fn parse_token<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::at(input, token, expected))
+}
+
+fn position(input: &str, token: &str) -> (usize, usize) {
+    let start = input.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize)
+        .checked_sub(start)
+        .filter(|&offset| offset <= input.len())
+        .unwrap_or(input.len());
+
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+    (line, before[line_start..].chars().count() + 1)
+}
+
+#[test]
+fn test_error_position() {
+    let input = "1,2\n3,x\n";
+    let token = &input[6..7];
+    let error = ParseError::at(input, token, "a number").with_file("test");
+
+    assert_eq!((error.line(), error.column()), (2, 3));
+    assert_eq!(error.to_string(), "test:2:3: expected a number, found \"x\"");
+}
+
+#[test]
+fn test_error_at_end() {
+    let error = ParseError::at_end("abc\ndef\n", "another line");
+
+    assert_eq!((error.line(), error.column()), (2, 4));
+    assert_eq!(error.to_string(), "line 2, column 4: expected another line, found end of input");
+}
+
+#[test]
+fn test_solve_error() {
+    let error = SolveError::from(ParseError::new(2, 3, "a number")).in_part(Part::One).with_file("test");
+    assert_eq!(error.to_string(), "part 1: test:2:3: expected a number");
+    assert_eq!(SolveError::no_answer("no board ever wins").in_part(Part::Two).to_string(), "part 2: no board ever wins");
+}
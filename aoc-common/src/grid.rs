@@ -0,0 +1,214 @@
+use crate::ParseError;
+use std::ops::{Index, IndexMut};
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (1, -1), (-1, 1), (1, 1)];
+
+/// A rectangle of cells stored row by row, addressed by `(x, y)` with `(0, 0)` at the top left.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(width: usize, height: usize, mut f: F) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+
+        Grid { width, height, cells }
+    }
+
+    /// Parses one row per line, converting each character with `cell`. `expected` describes
+    /// a valid character for the error when `cell` returns `None`.
+    pub fn parse<F: Fn(char) -> Option<T>>(input: &str, expected: &str, cell: F) -> Result<Grid<T>, ParseError> {
+        Grid::parse_from(input, input, expected, cell)
+    }
+
+    /// Like `parse`, for when the rows are only a part of `input`, so errors still point at
+    /// the right line of the whole input.
+    pub fn parse_from<F: Fn(char) -> Option<T>>(
+        input: &str,
+        rows: &str,
+        expected: &str,
+        cell: F
+    ) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for row in rows.trim_end().lines().map(str::trim_end) {
+            let row_width = row.chars().count();
+
+            match width {
+                None if row_width == 0 => return Err(ParseError::at(input, row, expected)),
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::at(input, row, format!("a row {} cells wide", width)));
+                },
+                Some(_) => ()
+            }
+
+            for (i, c) in row.char_indices() {
+                let value = cell(c).ok_or_else(|| ParseError::at(input, &row[i..i + c.len_utf8()], expected))?;
+                cells.push(value);
+            }
+
+            height += 1;
+        }
+
+        match width {
+            Some(width) => Ok(Grid { width, height, cells }),
+            None => Err(ParseError::at_end(input, "a grid row"))
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        match self.contains(position) {
+            true => Some(&self.cells[position.1 * self.width + position.0]),
+            false => None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        match self.contains(position) {
+            true => Some(&mut self.cells[position.1 * self.width + position.0]),
+            false => None
+        }
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// The positions above, left, right and below `position` that are inside the grid.
+    pub fn neighbours(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    /// The up to eight positions surrounding `position`, including diagonals.
+    pub fn neighbours_with_diagonals(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .chain(DIAGONAL.iter())
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    fn offset(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(position).then_some(position)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Draws the grid one line per row, without a trailing newline.
+    pub fn render<F: Fn(&T) -> char>(&self, f: F) -> String {
+        self.cells
+            .chunks(self.width.max(1))
+            .map(|row| row.iter().map(&f).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid { width, height, cells: vec!(value; width * height) }
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of single digits, such as a height map.
+    pub fn parse_digits(input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10).map(|d| d as u8))
+    }
+}
+
+impl Grid<bool> {
+    /// Parses a grid of `#` for set cells and `.` for clear ones.
+    pub fn parse_pixels(input: &str) -> Result<Grid<bool>, ParseError> {
+        Grid::parse(input, "'#' or '.'", pixel)
+    }
+}
+
+pub fn pixel(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", position, width, height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", position, width, height))
+    }
+}
+
+#[test]
+fn test_parse_and_neighbours() {
+    let grid = Grid::parse_digits("123\n456\n").unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 1)], 6);
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.neighbours((0, 0)).collect::<Vec<_>>(), vec!((1, 0), (0, 1)));
+    assert_eq!(grid.neighbours_with_diagonals((1, 0)).count(), 5);
+    assert_eq!(grid.render(|&d| (b'0' + d) as char), "123\n456");
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = Grid::parse_digits("123\n4x6\n").err().unwrap();
+    assert_eq!(error.to_string(), "line 2, column 2: expected a digit, found \"x\"");
+
+    let error = Grid::parse_pixels("#.#\n##\n").err().unwrap();
+    assert_eq!(error.to_string(), "line 2, column 1: expected a row 3 cells wide, found \"##\"");
+}
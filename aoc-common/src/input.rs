@@ -0,0 +1,92 @@
+use crate::Solver;
+use std::fmt;
+use std::fs;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Source {
+    /// The puzzle input bundled with the day.
+    #[default]
+    Puzzle,
+    /// One of the day's bundled examples, counting from 1.
+    Example(usize),
+    Stdin,
+    File(PathBuf)
+}
+
+impl Source {
+    pub fn read(&self, solver: &dyn Solver) -> io::Result<String> {
+        match self {
+            Source::Puzzle => Ok(solver.input().to_string()),
+            Source::Example(n) => solver
+                .examples()
+                .get(n.wrapping_sub(1))
+                .map(|example| example.to_string())
+                .ok_or_else(|| {
+                    let message = format!("day {} has {} examples", solver.day(), solver.examples().len());
+                    io::Error::new(io::ErrorKind::NotFound, message)
+                }),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            },
+            Source::File(path) => fs::read_to_string(path)
+        }
+    }
+
+    /// Opens the input to be read a line at a time, without loading all of stdin or a file
+    /// into memory first.
+    pub fn reader(&self, solver: &dyn Solver) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+            bundled => Ok(Box::new(Cursor::new(bundled.read(solver)?)))
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Puzzle => write!(f, "input"),
+            Source::Example(n) => write!(f, "example:{}", n),
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display())
+        }
+    }
+}
+
+/// Parses `-` as stdin, `input` as the bundled puzzle input, `example` or `example:N` as a
+/// bundled example, and anything else as a file path.
+impl FromStr for Source {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(Source::Stdin),
+            "input" => Ok(Source::Puzzle),
+            "example" => Ok(Source::Example(1)),
+            _ => match s.strip_prefix("example:") {
+                Some(n) => match n.parse() {
+                    Ok(n) if n > 0 => Ok(Source::Example(n)),
+                    _ => Err(format!("'{}' is not an example number", n))
+                },
+                None => Ok(Source::File(PathBuf::from(s)))
+            }
+        }
+    }
+}
+
+#[test]
+fn test_source_from_str() {
+    assert_eq!("-".parse(), Ok(Source::Stdin));
+    assert_eq!("example".parse(), Ok(Source::Example(1)));
+    assert_eq!("example:3".parse(), Ok(Source::Example(3)));
+    assert_eq!("day-2/test".parse(), Ok(Source::File(PathBuf::from("day-2/test"))));
+    assert!("example:0".parse::<Source>().is_err());
+}
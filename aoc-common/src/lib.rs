@@ -0,0 +1,240 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+pub mod count;
+mod error;
+pub mod grid;
+mod input;
+pub mod param;
+pub mod parse;
+pub mod random;
+pub mod search;
+pub mod trace;
+pub mod window;
+
+pub use count::{Count, Integer, Overflow};
+pub use error::{parse_token, ParseError, SolveError};
+pub use grid::Grid;
+pub use input::Source;
+pub use param::{Assignment, Param};
+pub use parse::Text;
+pub use random::Rng;
+pub use trace::{Frame, Tracer};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2")
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "one" => Ok(Part::One),
+            "2" | "two" => Ok(Part::Two),
+            _ => Err(format!("'{}' is not a puzzle part, expected 1 or 2", s))
+        }
+    }
+}
+
+/// A single day's puzzle, split into parsing the raw input and solving each part from it.
+pub trait Solution {
+    const DAY: u8;
+
+    /// The puzzle input, bundled into the binary so it runs from any directory.
+    const INPUT: &'static str;
+
+    /// The worked examples from the puzzle description.
+    const EXAMPLES: &'static [&'static str];
+
+    type Input;
+    type PartOne: fmt::Display;
+    type PartTwo: fmt::Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solves part one, failing if the input parsed but has no answer.
+    fn part_one(&self, input: &Self::Input) -> Result<Self::PartOne, SolveError>;
+    fn part_two(&self, input: &Self::Input) -> Result<Self::PartTwo, SolveError>;
+
+    /// Solves `part` while sending each step of the simulation to `tracer`, returning false
+    /// if the part is not something that can be watched step by step.
+    fn trace(&self, _input: &Self::Input, _part: Part, _tracer: &mut dyn Tracer) -> bool {
+        false
+    }
+
+    /// The puzzle's named parameters, like how many steps to run, which can be changed before
+    /// solving. Most days have none.
+    fn params(&mut self) -> Vec<Param<'_>> {
+        Vec::new()
+    }
+
+    /// Makes up a random input in this day's format, about `scale` times the size of a real
+    /// puzzle input, or returns None if the day has no generator.
+    fn generate(&self, _rng: &mut Rng, _scale: usize) -> Option<String> {
+        None
+    }
+}
+
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub elapsed: Duration
+}
+
+/// The answers from one run of a solver, along with how long parsing took.
+pub struct Solved {
+    pub parse_elapsed: Duration,
+    pub answers: Vec<Answer>
+}
+
+/// Type-erased view of a `Solution`, so the runner can hold every day in one collection and
+/// share it between threads.
+pub trait Solver: Send + Sync {
+    fn day(&self) -> u8;
+    fn input(&self) -> &'static str;
+    fn examples(&self) -> &'static [&'static str];
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Solved, SolveError>;
+    fn trace(&self, input: &str, part: Part, tracer: &mut dyn Tracer) -> Result<bool, ParseError>;
+    fn generate(&self, seed: u64, scale: usize) -> Option<String>;
+    fn params(&mut self) -> Vec<Param<'_>>;
+
+    /// Changes one of the named parameters.
+    fn set(&mut self, assignment: &Assignment) -> Result<(), String> {
+        let day = self.day();
+        let mut params = self.params();
+        let names = params.iter().map(|param| param.name).collect::<Vec<&str>>().join(", ");
+
+        let param = params
+            .iter_mut()
+            .find(|param| param.name == assignment.name)
+            .ok_or_else(|| match names.is_empty() {
+                true => format!("Day {} has no parameters", day),
+                false => format!("Day {} has no parameter '{}', expected one of {}", day, assignment.name, names)
+            })?;
+
+        param.value.set(&assignment.value).map_err(|e| format!("{} for {}", e, assignment.name))
+    }
+}
+
+impl<S: Solution + Send + Sync> Solver for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn input(&self) -> &'static str {
+        S::INPUT
+    }
+
+    fn examples(&self) -> &'static [&'static str] {
+        S::EXAMPLES
+    }
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Solved, SolveError> {
+        let start = Instant::now();
+        let input = self.parse(input)?;
+        let parse_elapsed = start.elapsed();
+
+        let answers = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let value = match part {
+                    Part::One => self.part_one(&input).map(|answer| answer.to_string()),
+                    Part::Two => self.part_two(&input).map(|answer| answer.to_string())
+                };
+
+                value
+                    .map(|value| Answer { part, value, elapsed: start.elapsed() })
+                    .map_err(|e| e.in_part(part))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Solved { parse_elapsed, answers })
+    }
+
+    fn trace(&self, input: &str, part: Part, tracer: &mut dyn Tracer) -> Result<bool, ParseError> {
+        let input = self.parse(input)?;
+        Ok(Solution::trace(self, &input, part, tracer))
+    }
+
+    fn generate(&self, seed: u64, scale: usize) -> Option<String> {
+        Solution::generate(self, &mut Rng::new(seed), scale)
+    }
+
+    fn params(&mut self) -> Vec<Param<'_>> {
+        Solution::params(self)
+    }
+}
+
+/// Solves one of a solver's examples, numbered from 1, and panics unless `part` gives
+/// `expected`. Trailing whitespace on each line is ignored, so drawings compare cleanly.
+pub fn assert_example(solver: &dyn Solver, example: usize, part: Part, expected: &str) {
+    let normalize = |answer: &str| answer.trim_end().lines().map(str::trim_end).collect::<Vec<&str>>().join("\n");
+    let input = solver.examples()[example - 1];
+    let solved = solver
+        .solve(input, &[part])
+        .unwrap_or_else(|e| panic!("Day {} example {}: {}", solver.day(), example, e));
+
+    assert_eq!(
+        normalize(&solved.answers[0].value),
+        normalize(expected),
+        "Day {} example {} part {}",
+        solver.day(),
+        example,
+        part
+    );
+}
+
+#[test]
+fn test_part_from_str() {
+    assert_eq!("1".parse(), Ok(Part::One));
+    assert_eq!("two".parse(), Ok(Part::Two));
+    assert!("3".parse::<Part>().is_err());
+}
+
+#[test]
+fn test_solver_parses_once_for_both_parts() {
+    struct Lengths;
+
+    impl Solution for Lengths {
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "";
+        const EXAMPLES: &'static [&'static str] = &[];
+
+        type Input = Vec<usize>;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            match input.is_empty() {
+                true => Err(ParseError::at_end(input, "a line")),
+                false => Ok(input.lines().map(str::len).collect())
+            }
+        }
+
+        fn part_one(&self, input: &Self::Input) -> Result<usize, SolveError> { Ok(input.iter().sum()) }
+        fn part_two(&self, input: &Self::Input) -> Result<usize, SolveError> { Ok(input.len()) }
+    }
+
+    let answers = Lengths.solve("ab\ncde", &Part::ALL).unwrap().answers;
+    assert_eq!(answers[0].value, "5");
+    assert_eq!(answers[1].value, "2");
+    assert!(Lengths.solve("", &[Part::One]).is_err());
+}
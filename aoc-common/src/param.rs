@@ -0,0 +1,65 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Something a parameter can hold, shown and set as text.
+pub trait Value: fmt::Display {
+    fn set(&mut self, value: &str) -> Result<(), String>;
+}
+
+impl<T: FromStr + fmt::Display> Value for T {
+    fn set(&mut self, value: &str) -> Result<(), String> {
+        *self = value.parse().map_err(|_| format!("'{}' is not a valid value", value))?;
+        Ok(())
+    }
+}
+
+/// One of a day's named parameters, like how many days to simulate, borrowing the field that
+/// holds it so it can be changed without editing the solution.
+pub struct Param<'a> {
+    pub name: &'static str,
+    pub value: &'a mut dyn Value
+}
+
+impl<'a> Param<'a> {
+    pub fn new(name: &'static str, value: &'a mut dyn Value) -> Param<'a> {
+        Param { name, value }
+    }
+}
+
+/// A parameter to change, written `name=value` on the command line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Assignment {
+    pub name: String,
+    pub value: String
+}
+
+impl fmt::Display for Assignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.name, self.value)
+    }
+}
+
+impl FromStr for Assignment {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((name, value)) if !name.is_empty() => Ok(Assignment { name: name.to_string(), value: value.to_string() }),
+            _ => Err(format!("'{}' is not a parameter setting, expected name=value", s))
+        }
+    }
+}
+
+#[test]
+fn test_params() {
+    let mut steps = 10_usize;
+    let param = Param::new("steps", &mut steps);
+    param.value.set("40").unwrap();
+    assert_eq!(param.value.set("forty"), Err("'forty' is not a valid value".to_string()));
+    assert_eq!(param.value.to_string(), "40");
+
+    let assignment: Assignment = "part-one-steps=40".parse().unwrap();
+    assert_eq!((assignment.name.as_str(), assignment.value.as_str()), ("part-one-steps", "40"));
+    assert!("=40".parse::<Assignment>().is_err());
+    assert!("steps".parse::<Assignment>().is_err());
+}
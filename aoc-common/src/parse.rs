@@ -0,0 +1,211 @@
+//! Building blocks for reading puzzle input. Everything works on `Text`, a slice of the input
+//! that remembers the whole input, so any error can say which line and column it came from.
+//!
+//! The pieces compose: `text.list(",", integer("a number"))` reads `3,4,3,1,2`, and
+//! `text.lines_of(|line| line.key_value(" -> ", "a rule", key, value))` reads a line per rule.
+
+use crate::{Grid, ParseError};
+use std::iter;
+use std::str::FromStr;
+
+/// Part of the puzzle input, borrowed from it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Text<'a> {
+    input: &'a str,
+    text: &'a str
+}
+
+impl<'a> Text<'a> {
+    /// The whole of `input`.
+    pub fn new(input: &'a str) -> Text<'a> {
+        Text { input, text: input }
+    }
+
+    /// A smaller piece of this text, which must be borrowed from it.
+    fn slice(&self, text: &'a str) -> Text<'a> {
+        Text { input: self.input, text }
+    }
+
+    /// How far into the input this text starts.
+    fn offset(&self) -> usize {
+        self.text.as_ptr() as usize - self.input.as_ptr() as usize
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// An error pointing at this text.
+    pub fn error<S: Into<String>>(&self, expected: S) -> ParseError {
+        ParseError::at(self.input, self.text, expected)
+    }
+
+    /// An error for when this text ended before `expected` was read.
+    pub fn error_at_end<S: Into<String>>(&self, expected: S) -> ParseError {
+        let trimmed = self.text.trim_end_matches('\n');
+
+        match self.offset() + trimmed.len() == self.input.trim_end_matches('\n').len() {
+            true => ParseError::at_end(self.input, expected),
+            false => ParseError::at(self.input, &trimmed[trimmed.len()..], expected)
+        }
+    }
+
+    /// The empty text just after this one, for pointing at something that is missing.
+    pub fn end(&self) -> Text<'a> {
+        self.slice(&self.text[self.text.len()..])
+    }
+
+    pub fn trim(&self) -> Text<'a> {
+        self.slice(self.text.trim())
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Text<'a>> + 'a {
+        let text = *self;
+        self.text.lines().map(move |line| text.slice(line))
+    }
+
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Text<'a>> + 'a {
+        let text = *self;
+        self.text.split(separator).map(move |piece| text.slice(piece))
+    }
+
+    pub fn split_whitespace(&self) -> impl Iterator<Item = Text<'a>> + 'a {
+        let text = *self;
+        self.text.split_whitespace().map(move |piece| text.slice(piece))
+    }
+
+    /// The groups of lines between empty lines, skipping any extra empty lines.
+    pub fn sections(&self) -> impl Iterator<Item = Text<'a>> + 'a {
+        let text = *self;
+        let mut rest = self.text.trim_end();
+
+        iter::from_fn(move || {
+            rest = rest.trim_start_matches('\n');
+            if rest.is_empty() { return None; }
+
+            let (section, next) = rest.split_once("\n\n").unwrap_or((rest, ""));
+            rest = next;
+
+            Some(text.slice(section))
+        })
+    }
+
+    /// Splits the text in two at the first `separator`.
+    pub fn split_once(&self, separator: &str, expected: &str) -> Result<(Text<'a>, Text<'a>), ParseError> {
+        let (first, second) = self.text.split_once(separator).ok_or_else(|| self.error(expected))?;
+
+        Ok((self.slice(first), self.slice(second)))
+    }
+
+    /// The text after `prefix`, which must be there.
+    pub fn strip_prefix(&self, prefix: &str, expected: &str) -> Result<Text<'a>, ParseError> {
+        self.text
+            .strip_prefix(prefix)
+            .map(|rest| self.slice(rest))
+            .ok_or_else(|| self.error(expected))
+    }
+
+    /// The text itself, as long as every character is one `allowed` accepts. Otherwise the
+    /// error points at the first character that is not.
+    pub fn only<F: Fn(char) -> bool>(&self, allowed: F, expected: &str) -> Result<Text<'a>, ParseError> {
+        match self.text.char_indices().find(|&(_, c)| !allowed(c)) {
+            Some((i, c)) => Err(self.slice(&self.text[i..i + c.len_utf8()]).error(expected)),
+            None => Ok(*self)
+        }
+    }
+
+    /// The text as a single character.
+    pub fn char(&self, expected: &str) -> Result<char, ParseError> {
+        let mut chars = self.text.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(self.error(expected))
+        }
+    }
+
+    /// The text as any `FromStr` type, usually a number.
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        self.text.parse().map_err(|_| self.error(expected))
+    }
+
+    /// Every piece between `separator`s, each read by `item`.
+    pub fn list<T, F>(&self, separator: &'a str, item: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(Text<'a>) -> Result<T, ParseError>
+    {
+        self.split(separator).map(item).collect()
+    }
+
+    /// Every line, each read by `item`.
+    pub fn lines_of<T, F>(&self, item: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(Text<'a>) -> Result<T, ParseError>
+    {
+        self.lines().map(item).collect()
+    }
+
+    /// Every blank-line-separated section, each read by `item`.
+    pub fn sections_of<T, F>(&self, item: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(Text<'a>) -> Result<T, ParseError>
+    {
+        self.sections().map(item).collect()
+    }
+
+    /// A key and value either side of `separator`, like `CH -> B` or `x=5`.
+    pub fn key_value<K, V, FK, FV>(&self, separator: &str, expected: &str, key: FK, value: FV) -> Result<(K, V), ParseError>
+    where
+        FK: FnOnce(Text<'a>) -> Result<K, ParseError>,
+        FV: FnOnce(Text<'a>) -> Result<V, ParseError>
+    {
+        let (first, second) = self.split_once(separator, expected)?;
+
+        Ok((key(first)?, value(second)?))
+    }
+
+    /// A rectangle of characters, each turned into a cell by `cell`.
+    pub fn grid<T, F: Fn(char) -> Option<T>>(&self, expected: &str, cell: F) -> Result<Grid<T>, ParseError> {
+        Grid::parse_from(self.input, self.text, expected, cell)
+    }
+}
+
+/// Reads a whole piece of text as a number, or anything else that implements `FromStr`.
+pub fn integer<'a, T: FromStr>(expected: &'a str) -> impl Fn(Text<'_>) -> Result<T, ParseError> + 'a {
+    move |text| text.parse(expected)
+}
+
+#[test]
+fn test_combinators() {
+    let input = "3,4,3\n\nCH -> B\nHH -> N\n\n\n#.\n.#\n";
+    let sections: Vec<Text> = Text::new(input).sections().collect();
+    let sizes: Vec<&str> = sections.iter().map(Text::as_str).collect();
+    assert_eq!(sizes, ["3,4,3", "CH -> B\nHH -> N", "#.\n.#"]);
+
+    assert_eq!(sections[0].list(",", integer::<u8>("a number")), Ok(vec!(3, 4, 3)));
+
+    let rules = sections[1].lines_of(|line| line.key_value(" -> ", "a rule", |pair| Ok(pair.as_str()), |c| c.char("an element")));
+    assert_eq!(rules, Ok(vec!(("CH", 'B'), ("HH", 'N'))));
+    assert_eq!(sections[2].grid("a pixel", crate::grid::pixel).unwrap().render(|&lit| if lit { '#' } else { '.' }), "#.\n.#");
+
+    let error = Text::new("1,x").list(",", integer::<u8>("a number")).unwrap_err();
+    assert_eq!(error.to_string(), "line 1, column 3: expected a number, found \"x\"");
+}
+
+#[test]
+fn test_error_positions() {
+    let input = "fold along y=7\nfold along z=2\n";
+    let text = Text::new(input);
+    let line = text.lines().nth(1).unwrap();
+
+    let (axis, _) = line.strip_prefix("fold along ", "'fold along'").unwrap().split_once("=", "an axis").unwrap();
+    assert_eq!(axis.only(|c| c == 'x' || c == 'y', "x or y").unwrap_err().to_string(), "line 2, column 12: expected x or y, found \"z\"");
+    assert_eq!(text.error_at_end("a fold").to_string(), "line 2, column 15: expected a fold, found end of input");
+    assert_eq!(text.split_once("|", "two halves").unwrap_err().line(), 1);
+    assert_eq!(line.error_at_end("more").to_string(), "line 2, column 15: expected more, found end of input");
+    assert_eq!(text.lines().next().unwrap().error_at_end("more").to_string(), "line 1, column 15: expected more, found nothing");
+}
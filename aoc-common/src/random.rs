@@ -0,0 +1,67 @@
+/// A small seeded random number generator, so a generated input is the same for the same
+/// seed on every machine. This is SplitMix64, which is fast and plenty random for making
+/// puzzle inputs, but nothing else.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from 0 up to but not including `bound`, which must not be 0.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "no numbers are below 0");
+
+        // Multiplying rather than taking the remainder keeps the bias down to nothing we'd notice
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// A number from `low` to `high`, both included.
+    pub fn between(&mut self, low: usize, high: usize) -> usize {
+        low + self.below(high - low + 1)
+    }
+
+    /// True one time in `n`.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[test]
+fn test_rng() {
+    let mut rng = Rng::new(1234);
+    let first: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+    let mut again = Rng::new(1234);
+    assert_eq!(first, (0..4).map(|_| again.next_u64()).collect::<Vec<u64>>());
+    assert_ne!(first[0], Rng::new(1235).next_u64());
+
+    assert!((0..1000).all(|_| rng.between(3, 5) >= 3 && rng.between(3, 5) <= 5));
+
+    let mut numbers: Vec<usize> = (0..50).collect();
+    rng.shuffle(&mut numbers);
+    assert_ne!(numbers, (0..50).collect::<Vec<usize>>());
+    numbers.sort();
+    assert_eq!(numbers, (0..50).collect::<Vec<usize>>());
+}
@@ -0,0 +1,237 @@
+use crate::Grid;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Anything that can be searched by following edges from one node to the next.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    fn neighbours(&self, node: &Self::Node) -> impl Iterator<Item = Self::Node>;
+}
+
+/// A graph where each edge has a cost, for the cheapest-path searches.
+pub trait Weighted: Graph {
+    fn cost(&self, from: &Self::Node, to: &Self::Node) -> usize;
+}
+
+/// A grid is a graph of its cells, each joined to the cells above, below, left and right.
+impl<T> Graph for Grid<T> {
+    type Node = (usize, usize);
+
+    fn neighbours(&self, &position: &(usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        Grid::neighbours(self, position)
+    }
+}
+
+/// Moving onto a cell of a grid of digits costs the digit, like the risk levels of day 15.
+impl Weighted for Grid<u8> {
+    fn cost(&self, _: &(usize, usize), to: &(usize, usize)) -> usize {
+        self[*to] as usize
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N> {
+    pub cost: usize,
+    /// Every node from the start to the goal, both included.
+    pub nodes: Vec<N>
+}
+
+/// Every node found so far, with the node it was first reached from.
+struct Visited<N> {
+    nodes: Vec<N>,
+    parents: Vec<Option<usize>>,
+    indices: HashMap<N, usize>
+}
+
+impl<N: Clone + Eq + Hash> Visited<N> {
+    fn new() -> Visited<N> {
+        Visited { nodes: Vec::new(), parents: Vec::new(), indices: HashMap::new() }
+    }
+
+    /// Records `node` if it is new, returning its index and whether it was.
+    fn insert(&mut self, node: &N, parent: Option<usize>) -> (usize, bool) {
+        match self.indices.get(node) {
+            Some(&index) => (index, false),
+            None => {
+                self.indices.insert(node.clone(), self.nodes.len());
+                self.nodes.push(node.clone());
+                self.parents.push(parent);
+                (self.nodes.len() - 1, true)
+            }
+        }
+    }
+
+    fn path(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec!(self.nodes[index].clone());
+
+        while let Some(parent) = self.parents[index] {
+            path.push(self.nodes[parent].clone());
+            index = parent;
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+fn unweighted<G: Graph, F: FnMut(&G::Node) -> bool>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: F,
+    depth_first: bool
+) -> Option<Vec<G::Node>> {
+    let mut visited = Visited::new();
+    let mut frontier = VecDeque::from([(start, None)]);
+
+    while let Some((node, parent)) = if depth_first { frontier.pop_back() } else { frontier.pop_front() } {
+        let (index, new) = visited.insert(&node, parent);
+
+        if !new { continue; }
+        if is_goal(&node) { return Some(visited.path(index)); }
+
+        for neighbour in graph.neighbours(&node) {
+            if !visited.indices.contains_key(&neighbour) { frontier.push_back((neighbour, Some(index))); }
+        }
+    }
+
+    None
+}
+
+/// Finds a path to the nearest node matching `is_goal`, counting every edge as one step.
+pub fn bfs<G: Graph, F: FnMut(&G::Node) -> bool>(graph: &G, start: G::Node, is_goal: F) -> Option<Vec<G::Node>> {
+    unweighted(graph, start, is_goal, false)
+}
+
+/// Finds some path to a node matching `is_goal`, going as deep as possible before backtracking.
+pub fn dfs<G: Graph, F: FnMut(&G::Node) -> bool>(graph: &G, start: G::Node, is_goal: F) -> Option<Vec<G::Node>> {
+    unweighted(graph, start, is_goal, true)
+}
+
+/// Every node reachable from `start` using only the edges `passable` allows, in the order
+/// they were reached.
+pub fn flood_fill<G: Graph, F: FnMut(&G::Node, &G::Node) -> bool>(
+    graph: &G,
+    start: G::Node,
+    mut passable: F
+) -> Vec<G::Node> {
+    let mut visited = Visited::new();
+    let mut frontier = VecDeque::from([visited.insert(&start, None).0]);
+
+    while let Some(index) = frontier.pop_front() {
+        let node = visited.nodes[index].clone();
+
+        for neighbour in graph.neighbours(&node).filter(|neighbour| passable(&node, neighbour)) {
+            if let (next, true) = visited.insert(&neighbour, Some(index)) { frontier.push_back(next); }
+        }
+    }
+
+    visited.nodes
+}
+
+/// Finds the cheapest path to a node matching `is_goal`.
+pub fn dijkstra<G: Weighted, F: FnMut(&G::Node) -> bool>(graph: &G, start: G::Node, is_goal: F) -> Option<Path<G::Node>> {
+    astar(graph, start, is_goal, |_| 0)
+}
+
+/// Finds the cheapest path to a node matching `is_goal`, trying the nodes `heuristic` rates
+/// closest first. The heuristic must never overestimate the remaining cost.
+pub fn astar<G, F, H>(graph: &G, start: G::Node, mut is_goal: F, heuristic: H) -> Option<Path<G::Node>>
+where
+    G: Weighted,
+    F: FnMut(&G::Node) -> bool,
+    H: Fn(&G::Node) -> usize
+{
+    let mut visited = Visited::new();
+    let mut costs = vec!(0);
+    let mut done = vec!(false);
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), visited.insert(&start, None).0))]);
+
+    while let Some(Reverse((_, index))) = frontier.pop() {
+        if done[index] { continue; }
+        done[index] = true;
+
+        let node = visited.nodes[index].clone();
+
+        if is_goal(&node) {
+            return Some(Path { cost: costs[index], nodes: visited.path(index) });
+        }
+
+        for neighbour in graph.neighbours(&node) {
+            let cost = costs[index] + graph.cost(&node, &neighbour);
+            let (next, new) = visited.insert(&neighbour, Some(index));
+
+            if new {
+                costs.push(usize::MAX);
+                done.push(false);
+            }
+
+            if cost < costs[next] {
+                costs[next] = cost;
+                visited.parents[next] = Some(index);
+                frontier.push(Reverse((cost + heuristic(&neighbour), next)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Counts the distinct paths from `start` to nodes matching `is_goal`, which stop the path.
+/// The graph must have no cycles, or this never finishes.
+pub fn count_paths<G: Graph, F: Fn(&G::Node) -> bool>(graph: &G, start: G::Node, is_goal: F) -> usize {
+    fn count<G: Graph, F: Fn(&G::Node) -> bool>(
+        graph: &G,
+        node: &G::Node,
+        is_goal: &F,
+        counts: &mut HashMap<G::Node, usize>
+    ) -> usize {
+        if is_goal(node) { return 1; }
+        if let Some(&known) = counts.get(node) { return known; }
+
+        let total = graph.neighbours(node).map(|next| count(graph, &next, is_goal, counts)).sum();
+        counts.insert(node.clone(), total);
+        total
+    }
+
+    count(graph, &start, &is_goal, &mut HashMap::new())
+}
+
+#[test]
+fn test_grid_searches() {
+    let grid = Grid::parse_digits("1163\n1381\n2136\n").unwrap();
+
+    let path = bfs(&grid, (0, 0), |&p| p == (3, 2)).unwrap();
+    assert_eq!(path.len(), 6);
+    assert_eq!(dfs(&grid, (0, 0), |&p| p == (3, 2)).unwrap().last(), Some(&(3, 2)));
+
+    let cheapest = dijkstra(&grid, (0, 0), |&p| p == (3, 2)).unwrap();
+    let manhattan = |&(x, y): &(usize, usize)| (3 - x) + (2 - y);
+    assert_eq!(cheapest.cost, 13);
+    assert_eq!(cheapest.nodes, vec!((0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (3, 2)));
+    assert_eq!(astar(&grid, (0, 0), |&p| p == (3, 2), manhattan), Some(cheapest));
+
+    let low = flood_fill(&grid, (0, 0), |&from, &to| grid[to] <= grid[from] + 1);
+    assert_eq!(low, vec!((0, 0), (1, 0), (0, 1), (0, 2), (1, 2)));
+}
+
+#[test]
+fn test_count_paths() {
+    struct Diamond;
+
+    impl Graph for Diamond {
+        type Node = u8;
+
+        fn neighbours(&self, node: &u8) -> impl Iterator<Item = u8> {
+            match node {
+                0 => vec!(1, 2),
+                1 | 2 => vec!(3),
+                _ => vec!()
+            }.into_iter()
+        }
+    }
+
+    assert_eq!(count_paths(&Diamond, 0, |&n| n == 3), 2);
+    assert_eq!(bfs(&Diamond, 0, |&n| n == 4), None);
+}
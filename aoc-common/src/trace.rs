@@ -0,0 +1,132 @@
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// One step of a simulation, drawn as text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub label: String,
+    pub picture: String
+}
+
+impl Frame {
+    pub fn new<L: Into<String>, P: Into<String>>(label: L, picture: P) -> Frame {
+        Frame { label: label.into(), picture: picture.into() }
+    }
+}
+
+/// Receives the frames of a simulation as it runs.
+pub trait Tracer {
+    /// Whether frames are wanted at all, so simulations can skip drawing them.
+    fn enabled(&self) -> bool {
+        true
+    }
+
+    fn frame(&mut self, frame: Frame);
+}
+
+impl dyn Tracer + '_ {
+    /// Sends the frame built by `frame`, only building it if the tracer is enabled.
+    pub fn emit<F: FnOnce() -> Frame>(&mut self, frame: F) {
+        if self.enabled() {
+            self.frame(frame());
+        }
+    }
+}
+
+/// The tracer used when nobody is watching, which drops every frame.
+pub struct NoTrace;
+
+impl Tracer for NoTrace {
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn frame(&mut self, _: Frame) {}
+}
+
+/// Collects every frame, mostly for tests.
+#[derive(Default)]
+pub struct Frames(pub Vec<Frame>);
+
+impl Tracer for Frames {
+    fn frame(&mut self, frame: Frame) {
+        self.0.push(frame);
+    }
+}
+
+/// Prints each frame under its label, one after another, for reading back or diffing.
+pub struct PlainText<W: Write> {
+    out: W,
+    result: io::Result<()>
+}
+
+impl<W: Write> PlainText<W> {
+    pub fn new(out: W) -> PlainText<W> {
+        PlainText { out, result: Ok(()) }
+    }
+
+    /// Flushes the output, returning the first error hit while writing frames.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.result?;
+        self.out.flush()
+    }
+}
+
+impl<W: Write> Tracer for PlainText<W> {
+    fn frame(&mut self, frame: Frame) {
+        if self.result.is_ok() {
+            self.result = write!(self.out, "== {} ==\n{}\n\n", frame.label, frame.picture);
+        }
+    }
+}
+
+/// Redraws each frame in place with ANSI escape codes, pausing between frames so a terminal
+/// shows the simulation as an animation.
+pub struct Animation<W: Write> {
+    out: W,
+    delay: Duration,
+    result: io::Result<()>
+}
+
+impl<W: Write> Animation<W> {
+    pub fn new(out: W, delay: Duration) -> Animation<W> {
+        Animation { out, delay, result: Ok(()) }
+    }
+
+    /// Flushes the output, returning the first error hit while drawing frames.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.result?;
+        self.out.flush()
+    }
+}
+
+impl<W: Write> Tracer for Animation<W> {
+    fn frame(&mut self, frame: Frame) {
+        if self.result.is_err() {
+            return;
+        }
+
+        // Move to the top left and clear the screen, then draw over it
+        self.result = write!(self.out, "\x1b[H\x1b[2J{}\n{}\n", frame.label, frame.picture)
+            .and_then(|_| self.out.flush());
+
+        thread::sleep(self.delay);
+    }
+}
+
+#[test]
+fn test_renderers() {
+    let mut text = PlainText::new(Vec::new());
+    let tracer: &mut dyn Tracer = &mut text;
+    tracer.emit(|| Frame::new("Step 1", "#.\n.#"));
+    tracer.emit(|| Frame::new("Step 2", ".#\n#."));
+    assert_eq!(String::from_utf8(text.out).unwrap(), "== Step 1 ==\n#.\n.#\n\n== Step 2 ==\n.#\n#.\n\n");
+
+    let mut animation = Animation::new(Vec::new(), Duration::ZERO);
+    animation.frame(Frame::new("Step 1", "#"));
+    assert_eq!(String::from_utf8(animation.out).unwrap(), "\x1b[H\x1b[2JStep 1\n#\n");
+
+    let tracer: &mut dyn Tracer = &mut NoTrace;
+    tracer.emit(|| panic!("frames are not drawn without a tracer"));
+}
@@ -0,0 +1,178 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::iter::Sum;
+use std::str::FromStr;
+
+/// The last `width` items pushed into it, for sliding a window along items that arrive one
+/// at a time rather than from an iterator.
+pub struct Window<T> {
+    width: usize,
+    items: VecDeque<T>
+}
+
+impl<T> Window<T> {
+    /// Panics if `width` is 0.
+    pub fn new(width: usize) -> Window<T> {
+        assert!(width > 0, "a sliding window must hold at least one item");
+
+        Window { width, items: VecDeque::with_capacity(width) }
+    }
+
+    /// Adds `item`, dropping the oldest if the window was full, and returns the window once
+    /// `width` items have been pushed.
+    pub fn push(&mut self, item: T) -> Option<&[T]> {
+        if self.items.len() == self.width {
+            self.items.pop_front();
+        }
+
+        self.items.push_back(item);
+
+        match self.items.len() == self.width {
+            true => Some(self.items.make_contiguous()),
+            false => None
+        }
+    }
+}
+
+/// Iterator adapter that reduces every run of `width` consecutive items to one value, so
+/// `[1, 2, 3, 4]` summed in windows of 3 gives `[6, 9]`. Built with `SlidingWindows::sliding`.
+pub struct Sliding<I: Iterator, F> {
+    iterator: I,
+    window: Window<I::Item>,
+    reduce: F
+}
+
+impl<I, F, U> Iterator for Sliding<I, F>
+where
+    I: Iterator,
+    F: FnMut(&[I::Item]) -> U
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        loop {
+            let item = self.iterator.next()?;
+
+            if let Some(window) = self.window.push(item) {
+                return Some((self.reduce)(window));
+            }
+        }
+    }
+}
+
+pub trait SlidingWindows: Iterator + Sized {
+    /// Reduces each window of `width` items with `reduce`, which can be one of the functions
+    /// in this module. An iterator shorter than `width` gives nothing. Panics if `width` is 0.
+    fn sliding<F, U>(self, width: usize, reduce: F) -> Sliding<Self, F>
+    where
+        F: FnMut(&[Self::Item]) -> U
+    {
+        Sliding { iterator: self, window: Window::new(width), reduce }
+    }
+}
+
+impl<I: Iterator> SlidingWindows for I {}
+
+pub fn sum<T: Copy + Sum>(window: &[T]) -> T {
+    window.iter().copied().sum()
+}
+
+pub fn mean<T: Copy + Into<f64>>(window: &[T]) -> f64 {
+    window.iter().map(|&n| n.into()).sum::<f64>() / window.len() as f64
+}
+
+pub fn min<T: Copy + Ord>(window: &[T]) -> T {
+    *window.iter().min().expect("windows are never empty")
+}
+
+pub fn max<T: Copy + Ord>(window: &[T]) -> T {
+    *window.iter().max().expect("windows are never empty")
+}
+
+/// The middle value, or the mean of the middle two for windows of even width.
+pub fn median<T: Copy + Into<f64>>(window: &[T]) -> f64 {
+    let mut sorted: Vec<f64> = window.iter().map(|&n| n.into()).collect();
+    sorted.sort_by(f64::total_cmp);
+
+    let middle = sorted.len() / 2;
+    match sorted.len() % 2 {
+        0 => (sorted[middle - 1] + sorted[middle]) / 2.0,
+        _ => sorted[middle]
+    }
+}
+
+/// The reductions above by name, for choosing one at run time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reduction {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Median
+}
+
+impl Reduction {
+    /// Reduces `window`, giving every reduction's result as a float so they all compare alike.
+    /// Sums are taken in a `u64`, so a window of large items cannot overflow the item type.
+    pub fn apply<T: Copy + Ord + Into<u64> + Into<f64>>(self, window: &[T]) -> f64 {
+        match self {
+            Reduction::Sum => window.iter().map(|&n| Into::<u64>::into(n)).sum::<u64>() as f64,
+            Reduction::Mean => mean(window),
+            Reduction::Min => min(window).into(),
+            Reduction::Max => max(window).into(),
+            Reduction::Median => median(window)
+        }
+    }
+}
+
+impl fmt::Display for Reduction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reduction::Sum => write!(f, "sum"),
+            Reduction::Mean => write!(f, "mean"),
+            Reduction::Min => write!(f, "min"),
+            Reduction::Max => write!(f, "max"),
+            Reduction::Median => write!(f, "median")
+        }
+    }
+}
+
+impl FromStr for Reduction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sum" => Ok(Reduction::Sum),
+            "mean" => Ok(Reduction::Mean),
+            "min" => Ok(Reduction::Min),
+            "max" => Ok(Reduction::Max),
+            "median" => Ok(Reduction::Median),
+            _ => Err(format!("'{}' is not a reduction, expected sum, mean, min, max or median", s))
+        }
+    }
+}
+
+#[test]
+fn test_sliding() {
+    let depths = [4_u32, 1, 3, 2, 8];
+
+    assert_eq!(depths.into_iter().sliding(3, sum).collect::<Vec<u32>>(), vec!(8, 6, 13));
+    assert_eq!(depths.into_iter().sliding(2, mean).collect::<Vec<f64>>(), vec!(2.5, 2.0, 2.5, 5.0));
+    assert_eq!(depths.into_iter().sliding(3, min).collect::<Vec<u32>>(), vec!(1, 1, 2));
+    assert_eq!(depths.into_iter().sliding(3, max).collect::<Vec<u32>>(), vec!(4, 3, 8));
+    assert_eq!(depths.into_iter().sliding(4, median).collect::<Vec<f64>>(), vec!(2.5, 2.5));
+    assert_eq!(depths.into_iter().sliding(1, sum).count(), 5);
+    assert_eq!(depths.into_iter().sliding(6, sum).count(), 0);
+
+    assert_eq!(Reduction::Max.apply(&depths), 8.0);
+    assert_eq!(Reduction::Median.apply(&depths), 3.0);
+    assert_eq!(Reduction::Sum.apply(&[u32::MAX, u32::MAX]), 2.0 * u32::MAX as f64);
+
+    let mut window = Window::new(2);
+    assert_eq!(window.push(1), None);
+    assert_eq!(window.push(2), Some(&[1, 2][..]));
+    assert_eq!(window.push(3), Some(&[2, 3][..]));
+
+    assert_eq!("median".parse(), Ok(Reduction::Median));
+    assert!("mode".parse::<Reduction>().is_err());
+}
@@ -0,0 +1,151 @@
+use aoc_common::{Part, Solver};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Median timings for one day, stored in nanoseconds so baselines stay readable as JSON.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Timings {
+    pub parse_ns: u64,
+    pub part_one_ns: u64,
+    pub part_two_ns: u64
+}
+
+impl Timings {
+    fn stages(&self) -> [(&'static str, u64); 3] {
+        [("parse", self.parse_ns), ("part 1", self.part_one_ns), ("part 2", self.part_two_ns)]
+    }
+}
+
+pub type Baseline = BTreeMap<u8, Timings>;
+
+pub struct Settings {
+    /// The most samples to take of each day.
+    pub samples: usize,
+    /// Stop sampling a day once it has run for this long, as long as one sample was taken.
+    pub max_time: Duration
+}
+
+pub struct Regression {
+    pub day: u8,
+    pub stage: &'static str,
+    pub baseline_ns: u64,
+    pub current_ns: u64
+}
+
+impl Regression {
+    pub fn percent(&self) -> f64 {
+        (self.current_ns as f64 / self.baseline_ns as f64 - 1.0) * 100.0
+    }
+}
+
+fn median(samples: &mut [Duration]) -> u64 {
+    samples.sort();
+    samples[samples.len() / 2].as_nanos() as u64
+}
+
+/// Runs a warm up pass, then samples every stage of the solver against its bundled input.
+pub fn measure(solver: &dyn Solver, settings: &Settings) -> Result<Timings, String> {
+    let input = solver.input();
+    solver.solve(input, &Part::ALL).map_err(|e| e.to_string())?;
+
+    let mut parse = Vec::new();
+    let mut part_one = Vec::new();
+    let mut part_two = Vec::new();
+    let start = Instant::now();
+
+    while parse.len() < settings.samples && (parse.is_empty() || start.elapsed() < settings.max_time) {
+        let solved = solver.solve(input, &Part::ALL).map_err(|e| e.to_string())?;
+
+        parse.push(solved.parse_elapsed);
+        part_one.push(solved.answers[0].elapsed);
+        part_two.push(solved.answers[1].elapsed);
+    }
+
+    Ok(Timings {
+        parse_ns: median(&mut parse),
+        part_one_ns: median(&mut part_one),
+        part_two_ns: median(&mut part_two)
+    })
+}
+
+pub fn format_duration(nanos: u64) -> String {
+    let (value, unit) = match nanos {
+        n if n < 1_000 => return format!("{}ns", n),
+        n if n < 1_000_000 => (n as f64 / 1e3, "µs"),
+        n if n < 1_000_000_000 => (n as f64 / 1e6, "ms"),
+        n => (n as f64 / 1e9, "s")
+    };
+
+    match value {
+        v if v < 10.0 => format!("{:.2}{}", v, unit),
+        v if v < 100.0 => format!("{:.1}{}", v, unit),
+        v => format!("{:.0}{}", v, unit)
+    }
+}
+
+pub fn print_table(results: &Baseline) {
+    println!("{:>4}  {:>10}  {:>10}  {:>10}", "Day", "Parse", "Part 1", "Part 2");
+
+    for (day, timings) in results {
+        println!(
+            "{:>4}  {:>10}  {:>10}  {:>10}",
+            day,
+            format_duration(timings.parse_ns),
+            format_duration(timings.part_one_ns),
+            format_duration(timings.part_two_ns)
+        );
+    }
+}
+
+/// Finds every stage that is more than `threshold` percent slower than the baseline. Days
+/// missing from the baseline are skipped, since there is nothing to compare them to.
+pub fn regressions(baseline: &Baseline, current: &Baseline, threshold: f64) -> Vec<Regression> {
+    current
+        .iter()
+        .filter_map(|(day, timings)| Some((*day, baseline.get(day)?, timings)))
+        .flat_map(|(day, before, after)| {
+            before
+                .stages()
+                .into_iter()
+                .zip(after.stages())
+                .map(move |((stage, baseline_ns), (_, current_ns))| Regression { day, stage, baseline_ns, current_ns })
+        })
+        .filter(|regression| regression.percent() > threshold)
+        .collect()
+}
+
+pub fn load_baseline(path: &Path) -> Result<Baseline, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+
+    serde_json::from_str(&contents).map_err(|e| format!("Could not parse {}: {}", path.display(), e))
+}
+
+pub fn save_baseline(path: &Path, results: &Baseline) -> Result<(), String> {
+    let contents = serde_json::to_string_pretty(results).map_err(|e| e.to_string())?;
+    fs::write(path, contents + "\n").map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(512), "512ns");
+    assert_eq!(format_duration(52_345), "52.3µs");
+    assert_eq!(format_duration(1_214_000), "1.21ms");
+    assert_eq!(format_duration(54_200_000_000), "54.2s");
+}
+
+#[test]
+fn test_regressions() {
+    let timings = |parse_ns, part_one_ns, part_two_ns| Timings { parse_ns, part_one_ns, part_two_ns };
+    let baseline = Baseline::from([(1, timings(100, 100, 100)), (2, timings(100, 100, 100))]);
+    let current = Baseline::from([(1, timings(105, 150, 90)), (3, timings(500, 500, 500))]);
+
+    let found = regressions(&baseline, &current, 10.0);
+
+    assert_eq!(found.len(), 1);
+    assert_eq!((found[0].day, found[0].stage), (1, "part 1"));
+    assert_eq!(found[0].percent().round(), 50.0);
+}
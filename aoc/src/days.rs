@@ -0,0 +1,52 @@
+use aoc_common::Solver;
+
+pub fn all() -> Vec<Box<dyn Solver>> {
+    vec!(
+        Box::new(day_1::Day1::default()),
+        Box::new(day_2::Day2),
+        Box::new(day_3::Day3),
+        Box::new(day_4::Day4),
+        Box::new(day_5::Day5),
+        Box::new(day_6::Day6::default()),
+        Box::new(day_7::Day7),
+        Box::new(day_8::Day8),
+        Box::new(day_9::Day9),
+        Box::new(day_10::Day10),
+        Box::new(day_11::Day11::default()),
+        Box::new(day_12::Day12),
+        Box::new(day_13::Day13),
+        Box::new(day_14::Day14::default()),
+        Box::new(day_15::Day15::default()),
+        Box::new(day_16::Day16),
+        Box::new(day_17::Day17),
+        Box::new(day_20::Day20::default()),
+        Box::new(day_21::Day21::default()),
+    )
+}
+
+pub fn find(day: u8) -> Option<Box<dyn Solver>> {
+    all().into_iter().find(|solver| solver.day() == day)
+}
+
+/// The solvers for the requested days, or every solver if no days were requested.
+pub fn selected(days: &[u8]) -> Result<Vec<Box<dyn Solver>>, String> {
+    if let Some(day) = days.iter().find(|&&day| find(day).is_none()) {
+        return Err(format!("No solution for day {}", day));
+    }
+
+    Ok(all().into_iter().filter(|solver| days.is_empty() || days.contains(&solver.day())).collect())
+}
+
+#[test]
+fn test_days_are_unique() {
+    let days: Vec<u8> = all().iter().map(|solver| solver.day()).collect();
+    let mut deduplicated = days.clone();
+    deduplicated.dedup();
+
+    assert_eq!(days, deduplicated);
+    assert!(find(14).is_some());
+    assert!(find(18).is_none());
+    assert_eq!(selected(&[]).unwrap().len(), days.len());
+    assert_eq!(selected(&[2, 14]).unwrap().len(), 2);
+    assert!(selected(&[2, 18]).is_err());
+}
@@ -0,0 +1,466 @@
+use aoc_common::trace::{Animation, PlainText};
+use aoc_common::{Answer, Assignment, ParseError, Part, Solution, Solver, Source};
+use clap::{Parser, Subcommand};
+use output::Format;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
+
+mod bench;
+mod days;
+mod output;
+mod run_all;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day's solution
+    Run {
+        /// The puzzle day to run
+        day: u8,
+
+        /// Only run one part of the puzzle (1 or 2)
+        #[arg(long)]
+        part: Option<Part>,
+
+        /// Where to read input from: a file path, `-` for stdin, `input` for the bundled
+        /// puzzle input or `example[:N]` for a bundled example
+        #[arg(long, default_value_t)]
+        input: Source,
+
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+
+        /// Change one of the day's puzzle parameters, as listed by `list`
+        #[arg(long = "set", value_name = "NAME=VALUE")]
+        params: Vec<Assignment>
+    },
+
+    /// Show a simulation step by step (days 11, 13, 17 and 20)
+    Trace {
+        /// The puzzle day to trace
+        day: u8,
+
+        /// The part of the puzzle to trace (1 or 2)
+        #[arg(long, default_value_t = Part::One)]
+        part: Part,
+
+        /// Where to read input from, the same as for `run`
+        #[arg(long, default_value_t)]
+        input: Source,
+
+        /// Redraw each frame in place as a terminal animation instead of printing them all
+        #[arg(long)]
+        animate: bool,
+
+        /// Milliseconds to pause between animation frames
+        #[arg(long, default_value_t = 100)]
+        delay: u64,
+
+        /// Change one of the day's puzzle parameters, the same as for `run`
+        #[arg(long = "set", value_name = "NAME=VALUE")]
+        params: Vec<Assignment>
+    },
+
+    /// Stream sonar depths through day 1, solving both parts in one pass in constant memory
+    Stream {
+        /// Where to read depths from, the same as for `run` but defaulting to stdin
+        #[arg(long, default_value = "-")]
+        input: Source,
+
+        /// Print the running counts to stderr after this many depths, or never if 0
+        #[arg(long, default_value_t = 1_000_000)]
+        every: usize,
+
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+
+        /// Change one of day 1's puzzle parameters, the same as for `run`
+        #[arg(long = "set", value_name = "NAME=VALUE")]
+        params: Vec<Assignment>
+    },
+
+    /// Print statistics on a day 1 sonar sweep: runs, jumps, trends and anomalous readings
+    Profile {
+        /// Where to read depths from, the same as for `run`
+        #[arg(long, default_value_t)]
+        input: Source,
+
+        /// How many standard deviations a change must be from the mean to count as anomalous
+        #[arg(long, default_value_t = 3.0)]
+        threshold: f64,
+
+        /// Change one of day 1's puzzle parameters, such as the trend window
+        #[arg(long = "set", value_name = "NAME=VALUE")]
+        params: Vec<Assignment>
+    },
+
+    /// Print a random input for a day, for stress testing (days 4, 5, 12, 14, 16 and 20)
+    Generate {
+        /// The puzzle day to make an input for
+        day: u8,
+
+        /// Seed for the random numbers, the same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Roughly how many times bigger than a real puzzle input to make it
+        #[arg(long, default_value_t = 1)]
+        scale: usize
+    },
+
+    /// Solve both parts of every day at once across threads and summarise how it went
+    RunAll {
+        /// Only run these days
+        days: Vec<u8>,
+
+        /// Print a summary table, or the answers as JSON records
+        #[arg(long, value_enum, default_value_t)]
+        format: Format
+    },
+
+    /// Time parsing and each part of every day against its bundled input
+    Bench {
+        /// Only benchmark these days
+        days: Vec<u8>,
+
+        /// The most samples to take of each day
+        #[arg(long, default_value_t = 10)]
+        samples: usize,
+
+        /// Stop sampling a day after this many seconds
+        #[arg(long, default_value_t = 5.0)]
+        max_time: f64,
+
+        /// Write the results to a baseline JSON file
+        #[arg(long)]
+        save: Option<PathBuf>,
+
+        /// Compare the results against a saved baseline, failing on regressions
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Percentage slowdown allowed before a stage counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64
+    },
+
+    /// Check every day's answers for its bundled input against the known answers
+    Verify {
+        /// Only verify these days
+        days: Vec<u8>,
+
+        /// TOML file of known answers, defaults to the bundled answers.toml
+        #[arg(long)]
+        answers: Option<PathBuf>
+    },
+
+    /// List the days that have solutions, with their puzzle parameters
+    List
+}
+
+/// Finds the solver for `day` with its parameters changed as asked.
+fn configured(day: u8, params: &[Assignment]) -> Result<Box<dyn Solver>, String> {
+    let mut solver = days::find(day).ok_or(format!("No solution for day {}", day))?;
+
+    for assignment in params {
+        solver.set(assignment)?;
+    }
+
+    Ok(solver)
+}
+
+fn run(day: u8, part: Option<Part>, source: Source, format: Format, params: &[Assignment]) -> Result<(), String> {
+    let solver = configured(day, params)?;
+    let input = source
+        .read(solver.as_ref())
+        .map_err(|e| format!("Could not read {}: {}", source, e))?;
+
+    let parts = match part {
+        Some(part) => vec!(part),
+        None => Part::ALL.to_vec()
+    };
+
+    let solved = solver
+        .solve(&input, &parts)
+        .map_err(|e| e.with_file(source.to_string()).to_string())?;
+
+    for answer in &solved.answers {
+        output::print_answer(format, day, answer);
+    }
+
+    Ok(())
+}
+
+fn run_trace(
+    day: u8,
+    part: Part,
+    source: Source,
+    animate: bool,
+    delay: Duration,
+    params: &[Assignment]
+) -> Result<(), String> {
+    let solver = configured(day, params)?;
+    let input = source
+        .read(solver.as_ref())
+        .map_err(|e| format!("Could not read {}: {}", source, e))?;
+
+    let (traced, written) = match animate {
+        true => {
+            let mut tracer = Animation::new(io::stdout().lock(), delay);
+            (solver.trace(&input, part, &mut tracer), tracer.finish())
+        },
+        false => {
+            let mut tracer = PlainText::new(io::stdout().lock());
+            (solver.trace(&input, part, &mut tracer), tracer.finish())
+        }
+    };
+
+    let traced = traced.map_err(|e| e.with_file(source.to_string()).to_string())?;
+    written.map_err(|e| format!("Could not write frames: {}", e))?;
+
+    match traced {
+        true => Ok(()),
+        false => Err(format!("Day {} part {} cannot be traced", day, part))
+    }
+}
+
+fn run_stream(source: Source, every: usize, format: Format, params: &[Assignment]) -> Result<(), String> {
+    let mut day = day_1::Day1::default();
+
+    for assignment in params {
+        day.set(assignment)?;
+    }
+
+    let start = Instant::now();
+    let reader = source
+        .reader(&day)
+        .map_err(|e| format!("Could not read {}: {}", source, e))?;
+
+    let progress = day
+        .stream(reader, every, |progress| {
+            eprintln!("{} depths: part 1 {}, part 2 {}", progress.depths, progress.part_one, progress.part_two);
+        })
+        .map_err(|e| match e.get_ref().and_then(|inner| inner.downcast_ref::<ParseError>()) {
+            Some(parse_error) => parse_error.clone().with_file(source.to_string()).to_string(),
+            None => format!("Could not read {}: {}", source, e)
+        })?;
+
+    let elapsed = start.elapsed();
+    let answers = [(Part::One, progress.part_one), (Part::Two, progress.part_two)];
+
+    for (part, count) in answers {
+        output::print_answer(format, 1, &Answer { part, value: count.to_string(), elapsed });
+    }
+
+    Ok(())
+}
+
+fn run_profile(source: Source, threshold: f64, params: &[Assignment]) -> Result<(), String> {
+    let mut day = day_1::Day1::default();
+
+    for assignment in params {
+        day.set(assignment)?;
+    }
+
+    let input = source
+        .read(&day)
+        .map_err(|e| format!("Could not read {}: {}", source, e))?;
+    let depths = Solution::parse(&day, &input).map_err(|e| e.with_file(source.to_string()).to_string())?;
+
+    print!("{}", day.profile(&depths, threshold));
+    Ok(())
+}
+
+fn run_generate(day: u8, seed: u64, scale: usize) -> Result<(), String> {
+    let solver = days::find(day).ok_or(format!("No solution for day {}", day))?;
+
+    if scale == 0 {
+        return Err("The scale must be at least 1".to_string());
+    }
+
+    match solver.generate(seed, scale) {
+        Some(input) => {
+            print!("{}", input);
+            Ok(())
+        },
+        None => Err(format!("Day {} has no input generator", day))
+    }
+}
+
+fn run_every_day(days: Vec<u8>, format: Format) -> Result<(), String> {
+    let summary = run_all::run(&days::selected(&days)?);
+
+    match format {
+        Format::Text => run_all::print_table(&summary),
+        Format::Json => {
+            for job in &summary.jobs {
+                match &job.outcome {
+                    Ok(answer) => output::print_answer(format, job.day, answer),
+                    Err(message) => eprintln!("Day {} Part {}: {}", job.day, job.part, message)
+                }
+            }
+        }
+    }
+
+    match summary.failures() {
+        0 => Ok(()),
+        failures => Err(format!("{} parts failed", failures))
+    }
+}
+
+fn run_bench(
+    days: Vec<u8>,
+    samples: usize,
+    max_time: f64,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64
+) -> Result<(), String> {
+    if samples == 0 {
+        return Err("The number of samples must be at least 1".to_string());
+    }
+
+    let max_time = Duration::try_from_secs_f64(max_time)
+        .map_err(|_| format!("'{}' is not a valid time limit, expected a number of seconds from 0 up", max_time))?;
+    let settings = bench::Settings { samples, max_time };
+
+    if cfg!(debug_assertions) {
+        eprintln!("Warning: benchmarking a debug build, use --release for meaningful timings");
+    }
+
+    let baseline = baseline.map(|path| bench::load_baseline(&path)).transpose()?;
+    let mut results = bench::Baseline::new();
+
+    for solver in days::selected(&days)? {
+        let timings = bench::measure(solver.as_ref(), &settings)
+            .map_err(|e| format!("Day {}: {}", solver.day(), e))?;
+
+        results.insert(solver.day(), timings);
+    }
+
+    bench::print_table(&results);
+
+    if let Some(path) = save {
+        bench::save_baseline(&path, &results)?;
+    }
+
+    if let Some(baseline) = baseline {
+        let regressions = bench::regressions(&baseline, &results, threshold);
+
+        for regression in &regressions {
+            println!(
+                "Day {} {} regressed {:.1}%: {} -> {}",
+                regression.day,
+                regression.stage,
+                regression.percent(),
+                bench::format_duration(regression.baseline_ns),
+                bench::format_duration(regression.current_ns)
+            );
+        }
+
+        if !regressions.is_empty() {
+            return Err(format!("{} stages regressed by more than {}%", regressions.len(), threshold));
+        }
+    }
+
+    Ok(())
+}
+
+fn run_verify(days: Vec<u8>, answers: Option<PathBuf>) -> Result<(), String> {
+    let contents = match &answers {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))?,
+        None => verify::BUNDLED.to_string()
+    };
+
+    let answers = verify::Answers::parse(&contents)?;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for solver in days::selected(&days)? {
+        let day = solver.day();
+        let solved = match solver.solve(solver.input(), &Part::ALL) {
+            Ok(solved) => solved,
+            Err(e) => {
+                println!("Day {}: FAIL, {}", day, e.with_file("input"));
+                failed += Part::ALL.len();
+                continue;
+            }
+        };
+
+        for answer in solved.answers {
+            match verify::check(answers.expected(day, answer.part), &answer.value) {
+                verify::Outcome::Pass => {
+                    passed += 1;
+                    println!("Day {} Part {}: pass", day, answer.part);
+                },
+                verify::Outcome::Fail { expected, actual } => {
+                    failed += 1;
+                    match expected.contains('\n') || actual.contains('\n') {
+                        true => println!("Day {} Part {}: FAIL, expected\n{}\nbut got\n{}", day, answer.part, expected, actual),
+                        false => println!("Day {} Part {}: FAIL, expected {} but got {}", day, answer.part, expected, actual)
+                    }
+                },
+                verify::Outcome::Missing => {
+                    missing += 1;
+                    println!("Day {} Part {}: missing", day, answer.part);
+                }
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} answers did not match", failed))
+    }
+}
+
+fn main() {
+    let result = match Cli::parse().command {
+        Command::Run { day, part, input, format, params } => run(day, part, input, format, &params),
+        Command::Trace { day, part, input, animate, delay, params } => {
+            run_trace(day, part, input, animate, Duration::from_millis(delay), &params)
+        },
+        Command::Stream { input, every, format, params } => run_stream(input, every, format, &params),
+        Command::Profile { input, threshold, params } => run_profile(input, threshold, &params),
+        Command::Generate { day, seed, scale } => run_generate(day, seed, scale),
+        Command::RunAll { days, format } => run_every_day(days, format),
+        Command::Bench { days, samples, max_time, save, baseline, threshold } => {
+            run_bench(days, samples, max_time, save, baseline, threshold)
+        },
+        Command::Verify { days, answers } => run_verify(days, answers),
+        Command::List => {
+            for mut solver in days::all() {
+                let params: Vec<String> = solver
+                    .params()
+                    .iter()
+                    .map(|param| format!("{}={}", param.name, param.value))
+                    .collect();
+
+                match params.is_empty() {
+                    true => println!("Day {}", solver.day()),
+                    false => println!("Day {} ({})", solver.day(), params.join(", "))
+                }
+            }
+
+            Ok(())
+        }
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
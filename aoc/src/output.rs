@@ -0,0 +1,51 @@
+use aoc_common::{Answer, Part};
+use clap::ValueEnum;
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable lines like `Day 5 Part 1: 5169`
+    #[default]
+    Text,
+    /// One JSON object per answer and line, with the day, part, answer and elapsed_ns
+    Json
+}
+
+/// One answer as written by `--format json`.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    elapsed_ns: u64
+}
+
+fn format_record(day: u8, answer: &Answer) -> String {
+    let record = Record {
+        day,
+        part: match answer.part {
+            Part::One => 1,
+            Part::Two => 2
+        },
+        answer: &answer.value,
+        elapsed_ns: answer.elapsed.as_nanos() as u64
+    };
+
+    serde_json::to_string(&record).expect("records always serialize")
+}
+
+pub fn print_answer(format: Format, day: u8, answer: &Answer) {
+    match format {
+        Format::Json => println!("{}", format_record(day, answer)),
+        Format::Text if answer.value.contains('\n') => println!("Day {} Part {}:\n{}", day, answer.part, answer.value),
+        Format::Text => println!("Day {} Part {}: {}", day, answer.part, answer.value)
+    }
+}
+
+#[test]
+fn test_format_record() {
+    use std::time::Duration;
+
+    let answer = Answer { part: Part::Two, value: "X X\nXXX".to_string(), elapsed: Duration::from_micros(3) };
+    assert_eq!(format_record(13, &answer), r#"{"day":13,"part":2,"answer":"X X\nXXX","elapsed_ns":3000}"#);
+}
@@ -0,0 +1,181 @@
+use crate::bench::format_duration;
+use aoc_common::{Answer, Part, Solver};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How one part of one day went when run against its bundled input.
+pub struct Job {
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Result<Answer, String>,
+    /// Parsing and solving together, as seen by the thread that ran it.
+    pub elapsed: Duration
+}
+
+pub struct Summary {
+    pub jobs: Vec<Job>,
+    /// Wall clock time from starting the first job to finishing the last.
+    pub elapsed: Duration
+}
+
+impl Summary {
+    pub fn failures(&self) -> usize {
+        self.jobs.iter().filter(|job| job.outcome.is_err()).count()
+    }
+
+    /// The time spent in every job added up, which is how long running them one after
+    /// another would have taken.
+    pub fn busy(&self) -> Duration {
+        self.jobs.iter().map(|job| job.elapsed).sum()
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "an unknown panic".to_string()
+    }
+}
+
+/// Parses and solves one part, turning parse errors and panics into a failure message.
+fn solve(solver: &dyn Solver, part: Part) -> Result<Answer, String> {
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| solver.solve(solver.input(), &[part])))
+        .map_err(|payload| format!("panicked: {}", panic_message(payload.as_ref())))?
+        .map_err(|e| e.with_file("input").to_string())?;
+
+    Ok(solved.answers.into_iter().next().expect("one answer per requested part"))
+}
+
+/// Runs both parts of every solver at once, each on its own thread, and collects the results
+/// in day and part order.
+pub fn run(solvers: &[Box<dyn Solver>]) -> Summary {
+    let start = Instant::now();
+
+    let jobs = thread::scope(|scope| {
+        let handles: Vec<_> = solvers
+            .iter()
+            .flat_map(|solver| Part::ALL.iter().map(move |&part| (solver.as_ref(), part)))
+            .map(|(solver, part)| {
+                let handle = scope.spawn(move || {
+                    let start = Instant::now();
+                    (solve(solver, part), start.elapsed())
+                });
+
+                (solver.day(), part, handle)
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|(day, part, handle)| {
+                let (outcome, elapsed) = handle.join().expect("panics are caught inside the job");
+                Job { day, part, outcome, elapsed }
+            })
+            .collect()
+    });
+
+    Summary { jobs, elapsed: start.elapsed() }
+}
+
+/// Shortens an answer to fit on one row of the table.
+fn describe(answer: &str) -> String {
+    match answer.lines().count() {
+        0 | 1 => answer.to_string(),
+        lines => format!("({} line drawing)", lines)
+    }
+}
+
+fn format_row(job: &Job) -> String {
+    let (status, answer) = match &job.outcome {
+        Ok(answer) => ("ok", describe(&answer.value)),
+        Err(message) => ("FAILED", message.clone())
+    };
+
+    format!("{:>4}  {:>4}  {:<6}  {:>10}  {}", job.day, job.part.to_string(), status, format_duration(job.elapsed.as_nanos() as u64), answer)
+}
+
+pub fn print_table(summary: &Summary) {
+    println!("{:>4}  {:>4}  {:<6}  {:>10}  Answer", "Day", "Part", "Status", "Time");
+
+    for job in &summary.jobs {
+        println!("{}", format_row(job));
+    }
+
+    println!();
+    println!(
+        "{} of {} parts failed in {} ({} if run one after another)",
+        summary.failures(),
+        summary.jobs.len(),
+        format_duration(summary.elapsed.as_nanos() as u64),
+        format_duration(summary.busy().as_nanos() as u64)
+    );
+}
+
+#[test]
+fn test_failures_are_collected() {
+    use aoc_common::{ParseError, Solution, SolveError};
+
+    struct Fragile;
+
+    impl Solution for Fragile {
+        const DAY: u8 = 1;
+        const INPUT: &'static str = "3\n4\n";
+        const EXAMPLES: &'static [&'static str] = &[];
+
+        type Input = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = u32;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            input.lines().map(|line| aoc_common::parse_token(input, line, "a number")).collect()
+        }
+
+        fn part_one(&self, numbers: &Self::Input) -> Result<u32, SolveError> {
+            Ok(numbers.iter().sum())
+        }
+
+        fn part_two(&self, _: &Self::Input) -> Result<u32, SolveError> {
+            panic!("part two is not done yet")
+        }
+    }
+
+    struct Unparsable;
+
+    impl Solution for Unparsable {
+        const DAY: u8 = 2;
+        const INPUT: &'static str = "x";
+        const EXAMPLES: &'static [&'static str] = &[];
+
+        type Input = u32;
+        type PartOne = u32;
+        type PartTwo = u32;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            aoc_common::parse_token(input, input, "a number")
+        }
+
+        fn part_one(&self, number: &Self::Input) -> Result<u32, SolveError> {
+            Ok(*number)
+        }
+
+        fn part_two(&self, number: &Self::Input) -> Result<u32, SolveError> {
+            Ok(*number)
+        }
+    }
+
+    let summary = run(&[Box::new(Fragile), Box::new(Unparsable)]);
+    let outcomes: Vec<(u8, Part, Result<String, String>)> = summary
+        .jobs
+        .iter()
+        .map(|job| (job.day, job.part, job.outcome.as_ref().map(|answer| answer.value.clone()).map_err(Clone::clone)))
+        .collect();
+
+    assert_eq!(outcomes[0], (1, Part::One, Ok("7".to_string())));
+    assert_eq!(outcomes[1], (1, Part::Two, Err("panicked: part two is not done yet".to_string())));
+    assert_eq!(outcomes[2].2, Err("input:1:1: expected a number, found \"x\"".to_string()));
+    assert_eq!(summary.failures(), 3);
+    assert_eq!(describe("X X\nXXX\nX X"), "(3 line drawing)");
+}
@@ -0,0 +1,81 @@
+use aoc_common::Part;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// The answers file bundled with the runner, used when no other file is given.
+pub const BUNDLED: &str = include_str!("../../answers.toml");
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct DayAnswers {
+    part_one: Option<String>,
+    part_two: Option<String>
+}
+
+/// Known-correct answers keyed by day, read from a TOML file with a `[day-N]` table per day.
+pub struct Answers {
+    days: BTreeMap<u8, DayAnswers>
+}
+
+impl Answers {
+    pub fn parse(contents: &str) -> Result<Answers, String> {
+        let tables: BTreeMap<String, DayAnswers> = toml::from_str(contents).map_err(|e| e.to_string())?;
+        let mut days = BTreeMap::new();
+
+        for (key, answers) in tables {
+            let day = key
+                .strip_prefix("day-")
+                .and_then(|day| day.parse().ok())
+                .ok_or(format!("'{}' is not a day, expected a table like [day-1]", key))?;
+
+            days.insert(day, answers);
+        }
+
+        Ok(Answers { days })
+    }
+
+    pub fn expected(&self, day: u8, part: Part) -> Option<&str> {
+        let answers = self.days.get(&day)?;
+
+        match part {
+            Part::One => answers.part_one.as_deref(),
+            Part::Two => answers.part_two.as_deref()
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing
+}
+
+fn normalize(answer: &str) -> String {
+    answer.lines().map(str::trim_end).collect::<Vec<&str>>().join("\n")
+}
+
+pub fn check(expected: Option<&str>, actual: &str) -> Outcome {
+    match expected {
+        None => Outcome::Missing,
+        Some(expected) if normalize(expected) == normalize(actual) => Outcome::Pass,
+        Some(expected) => Outcome::Fail { expected: expected.to_string(), actual: actual.to_string() }
+    }
+}
+
+#[test]
+fn test_bundled_answers_parse() {
+    let answers = Answers::parse(BUNDLED).unwrap();
+    assert_eq!(answers.expected(1, Part::One), Some("1559"));
+    assert!(answers.expected(13, Part::Two).unwrap().contains('\n'));
+    assert_eq!(answers.expected(18, Part::One), None);
+}
+
+#[test]
+fn test_check() {
+    assert_eq!(check(Some("12"), "12"), Outcome::Pass);
+    assert_eq!(check(Some("X X\nXXX"), "X X  \nXXX"), Outcome::Pass);
+    assert_eq!(check(None, "12"), Outcome::Missing);
+    assert_eq!(check(Some("12"), "13"), Outcome::Fail { expected: "12".to_string(), actual: "13".to_string() });
+    assert!(Answers::parse("[one]\npart-one = \"1\"").is_err());
+}
@@ -0,0 +1,350 @@
+use aoc_common::parse::integer;
+use aoc_common::window::{Reduction, SlidingWindows, Window};
+use aoc_common::{Param, ParseError, Solution, SolveError, Text};
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::num::NonZeroUsize;
+#[cfg(test)]
+use aoc_common::window;
+
+fn parse_input(input: &str) -> Result<Vec<u32>, ParseError> {
+    Text::new(input).lines_of(integer("a depth"))
+}
+
+/// Counts how many values are larger than the one before, fed one value at a time.
+struct Increases<T> {
+    last_value: Option<T>,
+    count: u32
+}
+
+impl<T: PartialOrd> Increases<T> {
+    fn new() -> Increases<T> {
+        Increases { last_value: None, count: 0 }
+    }
+
+    fn push(&mut self, item: T) {
+        if self.last_value.as_ref().is_some_and(|last| item > *last) {
+            self.count += 1;
+        }
+
+        self.last_value = Some(item);
+    }
+}
+
+fn count_increases<T: PartialOrd, I: Iterator<Item = T>>(number_list: I) -> u32 {
+    let mut increases = Increases::new();
+    number_list.for_each(|item| increases.push(item));
+    increases.count
+}
+
+/// The running totals of a stream of depths.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    pub depths: usize,
+    pub part_one: u32,
+    pub part_two: u32
+}
+
+/// Consecutive readings that all kept going the same way, as an index into the readings and
+/// how many there were.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub len: usize
+}
+
+/// The change from one reading to the next, where `index` is the later reading.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Jump {
+    pub index: usize,
+    pub from: u32,
+    pub to: u32
+}
+
+impl Jump {
+    pub fn change(&self) -> i64 {
+        self.to as i64 - self.from as i64
+    }
+}
+
+/// Which way the depth went across a window, from its first reading to its last.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trend {
+    Rising,
+    Falling,
+    Level
+}
+
+impl Trend {
+    fn of(window: &[u32]) -> Trend {
+        match window[window.len() - 1].cmp(&window[0]) {
+            Ordering::Greater => Trend::Rising,
+            Ordering::Less => Trend::Falling,
+            Ordering::Equal => Trend::Level
+        }
+    }
+}
+
+/// A reading whose change from the one before is an outlier among all the changes, which
+/// flags glitches without flagging the steady descent of a normal sweep.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Anomaly {
+    pub index: usize,
+    pub depth: u32,
+    pub z_score: f64
+}
+
+/// Statistics on a whole sonar sweep, printable as a report.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Profile {
+    pub readings: usize,
+    pub shallowest: Option<u32>,
+    pub deepest: Option<u32>,
+    pub longest_increase: Option<Run>,
+    pub longest_decrease: Option<Run>,
+    pub largest_jump: Option<Jump>,
+    /// The trend of every window of `window` readings, in order.
+    pub window: usize,
+    pub trends: Vec<Trend>,
+    /// Readings whose change has a z-score beyond `threshold` either way.
+    pub threshold: f64,
+    pub anomalies: Vec<Anomaly>
+}
+
+fn longest_run(depths: &[u32], keeps_going: impl Fn(u32, u32) -> bool) -> Option<Run> {
+    let mut longest: Option<Run> = None;
+    let mut start = 0;
+
+    for i in 1..=depths.len() {
+        if i < depths.len() && keeps_going(depths[i - 1], depths[i]) { continue; }
+
+        let run = Run { start, len: i - start };
+        if run.len > 1 && longest.is_none_or(|longest| run.len > longest.len) {
+            longest = Some(run);
+        }
+
+        start = i;
+    }
+
+    longest
+}
+
+fn anomalies(depths: &[u32], threshold: f64) -> Vec<Anomaly> {
+    let changes: Vec<f64> = depths.windows(2).map(|pair| pair[1] as f64 - pair[0] as f64).collect();
+    if changes.is_empty() { return Vec::new(); }
+
+    let mean = changes.iter().sum::<f64>() / changes.len() as f64;
+    let deviation = (changes.iter().map(|change| (change - mean).powi(2)).sum::<f64>() / changes.len() as f64).sqrt();
+    if deviation == 0.0 { return Vec::new(); }
+
+    changes
+        .iter()
+        .enumerate()
+        .map(|(i, change)| Anomaly { index: i + 1, depth: depths[i + 1], z_score: (change - mean) / deviation })
+        .filter(|anomaly| anomaly.z_score.abs() > threshold)
+        .collect()
+}
+
+impl Profile {
+    /// Profiles `depths`, classifying windows `window` readings wide and flagging changes
+    /// more than `threshold` standard deviations from the mean change.
+    pub fn new(depths: &[u32], window: usize, threshold: f64) -> Profile {
+        Profile {
+            readings: depths.len(),
+            shallowest: depths.iter().copied().min(),
+            deepest: depths.iter().copied().max(),
+            longest_increase: longest_run(depths, |last, next| next > last),
+            longest_decrease: longest_run(depths, |last, next| next < last),
+            largest_jump: depths
+                .windows(2)
+                .enumerate()
+                .map(|(i, pair)| Jump { index: i + 1, from: pair[0], to: pair[1] })
+                .rev()
+                .max_by_key(|jump| jump.change().abs()),
+            window,
+            trends: depths.iter().copied().sliding(window, Trend::of).collect(),
+            threshold,
+            anomalies: anomalies(depths, threshold)
+        }
+    }
+
+    pub fn count(&self, trend: Trend) -> usize {
+        self.trends.iter().filter(|&&t| t == trend).count()
+    }
+}
+
+/// Writes the report with readings numbered from 1, the same as input lines.
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.shallowest, self.deepest) {
+            (Some(shallowest), Some(deepest)) => writeln!(f, "{} readings from {} to {}", self.readings, shallowest, deepest)?,
+            _ => return writeln!(f, "No readings")
+        }
+
+        let runs = [("Longest increase", self.longest_increase), ("Longest decrease", self.longest_decrease)];
+
+        for (name, run) in runs {
+            match run {
+                Some(Run { start, len }) => writeln!(f, "{}: {} readings from line {}", name, len, start + 1)?,
+                None => writeln!(f, "{}: none", name)?
+            }
+        }
+
+        if let Some(jump) = self.largest_jump {
+            writeln!(f, "Largest jump: {:+} at line {} ({} to {})", jump.change(), jump.index + 1, jump.from, jump.to)?;
+        }
+
+        writeln!(
+            f,
+            "Windows of {}: {} rising, {} falling, {} level",
+            self.window,
+            self.count(Trend::Rising),
+            self.count(Trend::Falling),
+            self.count(Trend::Level)
+        )?;
+
+        writeln!(f, "Anomalies beyond {} standard deviations: {}", self.threshold, self.anomalies.len())?;
+
+        for anomaly in &self.anomalies {
+            writeln!(f, "  line {}: {} (z = {:+.2})", anomaly.index + 1, anomaly.depth, anomaly.z_score)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Day1 {
+    /// How many depths part two compares at a time, and how each window is boiled down.
+    pub window: NonZeroUsize,
+    pub reduction: Reduction
+}
+
+impl Default for Day1 {
+    fn default() -> Day1 {
+        Day1 { window: NonZeroUsize::new(3).unwrap(), reduction: Reduction::Sum }
+    }
+}
+
+impl Day1 {
+    /// Profiles a whole sweep, with trends taken over windows as wide as part two's.
+    pub fn profile(&self, depths: &[u32], threshold: f64) -> Profile {
+        Profile::new(depths, self.window.get(), threshold)
+    }
+
+    /// Solves both parts in a single pass over `reader`, one depth per line, holding no more
+    /// than a window of depths in memory. `report` is given the totals after every `every`
+    /// depths, or never if `every` is 0.
+    pub fn stream<R, F>(&self, reader: R, every: usize, mut report: F) -> io::Result<Progress>
+    where
+        R: BufRead,
+        F: FnMut(&Progress)
+    {
+        let mut part_one = Increases::new();
+        let mut part_two = Increases::new();
+        let mut window = Window::new(self.window.get());
+        let mut progress = Progress::default();
+
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let depth: u32 = Text::new(&line)
+                .parse("a depth")
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.on_line(number + 1)))?;
+
+            part_one.push(depth);
+            if let Some(depths) = window.push(depth) {
+                part_two.push(self.reduction.apply(depths));
+            }
+
+            progress = Progress { depths: number + 1, part_one: part_one.count, part_two: part_two.count };
+
+            if every > 0 && progress.depths % every == 0 {
+                report(&progress);
+            }
+        }
+
+        Ok(progress)
+    }
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, numbers: &Self::Input) -> Result<u32, SolveError> {
+        Ok(count_increases(numbers.iter().copied()))
+    }
+
+    fn part_two(&self, numbers: &Self::Input) -> Result<u32, SolveError> {
+        let windows = numbers.iter().copied().sliding(self.window.get(), |window| self.reduction.apply(window));
+        Ok(count_increases(windows))
+    }
+
+    fn params(&mut self) -> Vec<Param<'_>> {
+        vec!(
+            Param::new("window", &mut self.window),
+            Param::new("reduction", &mut self.reduction)
+        )
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let values = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+    assert_eq!(count_increases(values.into_iter()), 7);
+}
+
+#[test]
+fn test_part_two() {
+    let values = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+    assert_eq!(count_increases(values.into_iter().sliding(3, window::sum)), 5);
+    assert_eq!(count_increases(values.into_iter().sliding(2, window::median)), 5);
+    assert_eq!(count_increases(values[..2].iter().copied().sliding(3, window::sum)), 0);
+}
+
+#[test]
+fn test_large_depths() {
+    // Three of these overflow a u32, which would wrap the first window round to look shallower
+    let input = "2000000000\n2000000000\n2000000000\n1\n";
+    let depths = Day1::default().parse(input).unwrap();
+
+    assert_eq!(Day1::default().part_two(&depths), Ok(0));
+    assert_eq!(Day1::default().stream(input.as_bytes(), 0, |_| ()).unwrap().part_two, 0);
+}
+
+#[test]
+fn test_stream() {
+    let mut reports = Vec::new();
+    let progress = Day1::default().stream(Day1::EXAMPLES[0].as_bytes(), 4, |progress| reports.push(*progress)).unwrap();
+
+    assert_eq!(progress, Progress { depths: 10, part_one: 7, part_two: 5 });
+    assert_eq!(reports.iter().map(|progress| progress.depths).collect::<Vec<usize>>(), vec!(4, 8));
+
+    let error = Day1::default().stream("1\n2\nx\n".as_bytes(), 0, |_| ()).unwrap_err();
+    assert_eq!(error.to_string(), "line 3, column 1: expected a depth, found \"x\"");
+}
+
+#[test]
+fn test_profile() {
+    let values = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+    let profile = Day1::default().profile(&values, 1.5);
+
+    assert_eq!(profile.longest_increase, Some(Run { start: 0, len: 4 }));
+    assert_eq!(profile.longest_decrease, Some(Run { start: 3, len: 2 }));
+    assert_eq!(profile.largest_jump, Some(Jump { index: 6, from: 207, to: 240 }));
+    assert_eq!((profile.count(Trend::Rising), profile.count(Trend::Falling)), (5, 3));
+    assert_eq!(profile.anomalies.iter().map(|anomaly| anomaly.index).collect::<Vec<usize>>(), vec!(6, 7));
+    assert!(profile.to_string().contains("Largest jump: +33 at line 7 (207 to 240)"));
+    assert_eq!(Profile::new(&[], 3, 1.5).to_string(), "No readings\n");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,133 @@
+use aoc_common::{ParseError, Solution, SolveError, Text};
+
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    Text::new(input).lines_of(|line| {
+        let line = line.only(|c| "()[]{}<>".contains(c), "a bracket")?;
+        Ok(line.as_str().to_string())
+    })
+}
+
+fn score_invalid_character(character: char) -> usize {
+    match character {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => panic!()
+    }
+}
+
+fn score_missing_ending(character: char) -> usize {
+    match character {
+        '(' => 1,
+        '[' => 2,
+        '{' => 3,
+        '<' => 4,
+        _ => panic!()
+    }
+}
+
+fn matching_brace(character: char) -> char {
+    match character {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        _ => panic!()
+    }
+}
+
+fn opening_character(character: char) -> bool {
+    matches!(character, '(' | '{' | '[' | '<')
+}
+
+/// How a line goes wrong, with its score. An incomplete line's score is `None` if it is too
+/// large to count.
+enum LineError {
+    Corrupted(usize),
+    Incomplete(Option<usize>)
+}
+
+fn score_program_line(line: &str) -> LineError {
+    let mut character_stack: Vec<char> = Vec::new();
+
+    for character in line.chars() {
+        if opening_character(character) {
+            character_stack.push(character);
+        } else {
+            let last_in = character_stack.pop();
+
+            if last_in.is_none() || matching_brace(last_in.unwrap()) != character {
+                return LineError::Corrupted(score_invalid_character(character));
+            }
+        }
+    }
+
+    let incomplete_score = character_stack
+        .iter()
+        .rev()
+        .map(|&character| score_missing_ending(character))
+        .try_fold(0_usize, |acc, n| acc.checked_mul(5)?.checked_add(n));
+
+    LineError::Incomplete(incomplete_score)
+}
+
+fn part_one(program: &[String]) -> usize {
+    program
+        .iter()
+        .map(|line| match score_program_line(line) { LineError::Corrupted(x) => x, _ => 0 } )
+        .sum()
+}
+
+fn part_two(program: &[String]) -> Result<usize, SolveError> {
+    let mut incomplete_lines: Vec<usize> = program
+        .iter()
+        .map(|line| score_program_line(line))
+        .filter_map(|error| match error { LineError::Incomplete(x) => Some(x), _ => None })
+        .collect::<Option<Vec<usize>>>()
+        .ok_or_else(|| SolveError::no_answer("a line is missing too many closing brackets to score"))?;
+
+    incomplete_lines.sort();
+
+    incomplete_lines.get(incomplete_lines.len() / 2).copied().ok_or_else(|| SolveError::no_answer("no line is incomplete"))
+}
+
+#[derive(Default)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, program: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part_one(program))
+    }
+
+    fn part_two(&self, program: &Self::Input) -> Result<usize, SolveError> {
+        part_two(program)
+    }
+}
+
+#[test]
+fn test_no_incomplete_lines() {
+    assert_eq!(part_two(&["(]".to_string(), "<)".to_string()]), Err(SolveError::no_answer("no line is incomplete")));
+}
+
+#[test]
+fn test_long_completion() {
+    // Each missing bracket multiplies the score by 5, so 30 of them no longer fit in 64 bits
+    let error = SolveError::no_answer("a line is missing too many closing brackets to score");
+    assert_eq!(part_two(&["(".repeat(30)]), Err(error));
+    assert!(part_two(&["(".repeat(20)]).is_ok());
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
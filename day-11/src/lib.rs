@@ -0,0 +1,116 @@
+use aoc_common::trace::NoTrace;
+#[cfg(test)]
+use aoc_common::trace::Frames;
+use aoc_common::{Frame, Grid, Param, ParseError, Part, Solution, SolveError, Tracer};
+
+fn step(state: &mut Grid<u8>) -> usize {
+    let mut add_queue: Vec<(usize, usize)> = state.positions().collect();
+
+    while let Some(position) = add_queue.pop() {
+        state[position] += 1;
+        if state[position] == 10 { add_queue.extend(state.neighbours_with_diagonals(position)); }
+    }
+
+    state
+        .iter_mut()
+        .map(|energy| {
+            match *energy > 9 {
+                true => { *energy = 0; 1 }
+                false => 0
+            }
+        })
+        .sum()
+}
+
+fn render(state: &Grid<u8>) -> String {
+    state.render(|&energy| (b'0' + energy) as char)
+}
+
+fn traced_step(state: &mut Grid<u8>, number: usize, tracer: &mut dyn Tracer) -> usize {
+    let flashes = step(state);
+    tracer.emit(|| Frame::new(format!("Step {}: {} flashes", number, flashes), render(state)));
+    flashes
+}
+
+fn part_one(state: &mut Grid<u8>, steps: usize, tracer: &mut dyn Tracer) -> usize {
+    (1..=steps)
+        .map(|number| traced_step(state, number, tracer))
+        .sum()
+}
+
+/// The first step on which every octopus flashes, if that happens within 10000 steps.
+fn part_two(state: &mut Grid<u8>, tracer: &mut dyn Tracer) -> Option<usize> {
+    (1..=10000_usize).find(|&number| traced_step(state, number, tracer) == state.len())
+}
+
+pub struct Day11 {
+    /// Steps to count flashes over in part one.
+    pub part_one_steps: usize
+}
+
+impl Default for Day11 {
+    fn default() -> Day11 {
+        Day11 { part_one_steps: 100 }
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = Grid<u8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_digits(input)
+    }
+
+    fn part_one(&self, state: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part_one(&mut state.clone(), self.part_one_steps, &mut NoTrace))
+    }
+
+    fn part_two(&self, state: &Self::Input) -> Result<usize, SolveError> {
+        part_two(&mut state.clone(), &mut NoTrace)
+            .ok_or_else(|| SolveError::no_answer("the octopuses do not all flash together within 10000 steps"))
+    }
+
+    fn trace(&self, state: &Self::Input, part: Part, tracer: &mut dyn Tracer) -> bool {
+        match part {
+            Part::One => { part_one(&mut state.clone(), self.part_one_steps, tracer); },
+            Part::Two => { part_two(&mut state.clone(), tracer); }
+        }
+
+        true
+    }
+
+    fn params(&mut self) -> Vec<Param<'_>> {
+        vec!(Param::new("part-one-steps", &mut self.part_one_steps))
+    }
+}
+
+#[test]
+fn test_non_square_grid() {
+    let input = Day11::default().parse("99\n99\n99\n").unwrap();
+    assert_eq!(part_two(&mut input.clone(), &mut NoTrace), Some(1));
+}
+
+#[test]
+fn test_trace() {
+    let mut frames = Frames::default();
+    assert!(Day11::default().trace(&Day11::default().parse(Day11::EXAMPLES[0]).unwrap(), Part::Two, &mut frames));
+
+    let last = frames.0.last().unwrap();
+    assert_eq!(frames.0.len(), 195);
+    assert_eq!(last.label, "Step 195: 100 flashes");
+    assert!(last.picture.lines().all(|row| row == "0000000000"));
+}
+
+#[test]
+fn test_params() {
+    let day = Day11 { part_one_steps: 10 };
+    assert_eq!(day.part_one(&day.parse(Day11::EXAMPLES[0]).unwrap()), Ok(204));
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
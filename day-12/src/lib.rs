@@ -0,0 +1,219 @@
+use aoc_common::search::{self, Graph};
+use aoc_common::{ParseError, Rng, Solution, SolveError, Text};
+use std::collections::BTreeSet;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Cave {
+    Large(String),
+    Small(String)
+}
+
+impl Cave {
+    fn name(&self) -> &str {
+        match self {
+            Cave::Large(name) => name,
+            Cave::Small(name) => name
+        }
+    }
+}
+
+impl From<&str> for Cave {
+    fn from(cave_string: &str) -> Self {
+        match cave_string.chars().all(|c| c.is_ascii_lowercase()) {
+            true => Cave::Small(cave_string.to_string()),
+            false => Cave::Large(cave_string.to_string())
+        }
+    }
+}
+
+pub struct Edge {
+    point_one: Cave,
+    point_two: Cave
+}
+
+
+fn parse_cave(name: Text) -> Result<Cave, ParseError> {
+    match !name.is_empty() && name.as_str().chars().all(|c| c.is_ascii_alphabetic()) {
+        true => Ok(Cave::from(name.as_str())),
+        false => Err(name.error("a cave name"))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Edge>, ParseError> {
+    Text::new(input).lines_of(|line| {
+        let (point_one, point_two) = line.key_value("-", "two caves separated by '-'", parse_cave, parse_cave)?;
+
+        // Two joined large caves could be walked between forever
+        match (&point_one, &point_two) {
+            (Cave::Large(_), Cave::Large(_)) => Err(line.error("at least one small cave")),
+            _ => Ok(Edge { point_one, point_two })
+        }
+    })
+}
+
+/// Where a path has got to, and the small caves it can no longer enter because of it.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Route<'a> {
+    cave: &'a Cave,
+    small_visited: BTreeSet<&'a str>,
+    small_visited_twice: bool
+}
+
+/// The caves as a graph of routes, so that each distinct path through them is one path
+/// through the graph.
+struct CaveSystem<'a> {
+    edges: &'a [Edge],
+    can_visit_small_cave_twice: bool
+}
+
+impl<'a> CaveSystem<'a> {
+    fn connected(&self, cave: &'a Cave) -> impl Iterator<Item = &'a Cave> + 'a {
+        self.edges.iter().filter_map(move |edge| {
+            match (&edge.point_one, &edge.point_two) {
+                (lv, next_visit) if lv.name() == cave.name() => Some(next_visit),
+                (next_visit, lv) if lv.name() == cave.name() => Some(next_visit),
+                _ => None
+            }
+        })
+    }
+
+    fn visit(&self, route: &Route<'a>, cave: &'a Cave) -> Option<Route<'a>> {
+        let mut next = Route { cave, ..route.clone() };
+
+        match cave {
+            Cave::Small(name) if name == "start" => return None,
+            Cave::Small(name) if !next.small_visited.insert(name) => {
+                match self.can_visit_small_cave_twice && !next.small_visited_twice {
+                    true => next.small_visited_twice = true,
+                    false => return None
+                }
+            },
+            _ => ()
+        }
+
+        Some(next)
+    }
+}
+
+impl<'a> Graph for CaveSystem<'a> {
+    type Node = Route<'a>;
+
+    fn neighbours(&self, route: &Route<'a>) -> impl Iterator<Item = Route<'a>> {
+        self.connected(route.cave).filter_map(move |cave| self.visit(route, cave))
+    }
+}
+
+fn traverse_cave(edges: &[Edge], can_visit_small_cave_twice: bool) -> usize {
+    let start = Cave::Small("start".to_string());
+    let initial = Route { cave: &start, small_visited: BTreeSet::new(), small_visited_twice: false };
+    let caves = CaveSystem { edges, can_visit_small_cave_twice };
+
+    search::count_paths(&caves, initial, |route| route.cave.name() == "end")
+}
+
+fn part_one(edges: &[Edge]) -> usize {
+    traverse_cave(edges, false)
+}
+
+fn part_two(edges: &[Edge]) -> usize {
+    traverse_cave(edges, true)
+}
+
+/// A name for the `index`th made up small cave, at least two letters long.
+fn cave_name(mut index: usize) -> String {
+    let mut name = String::new();
+
+    while index > 0 || name.len() < 2 {
+        name.push((b'a' + (index % 26) as u8) as char);
+        index /= 26;
+    }
+
+    name
+}
+
+/// Makes up `scale` clusters of four small and two large caves, each joined to the start
+/// and the end but not to each other, so the number of paths grows with the scale instead of
+/// exploding. Large caves are never joined to each other, which would allow endless paths.
+fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut names = (0..).map(cave_name).filter(|name| name != "end" && name != "start");
+    let mut edges = BTreeSet::new();
+
+    for _ in 0..scale {
+        let small: Vec<String> = names.by_ref().take(4).collect();
+        let large: Vec<String> = names.by_ref().take(2).map(|name| name.to_uppercase()).collect();
+        let caves: Vec<&String> = small.iter().chain(&large).collect();
+
+        for cave in &large {
+            for _ in 0..rng.between(2, 3) {
+                edges.insert((cave.clone(), rng.pick(&small).clone()));
+            }
+        }
+
+        for _ in 0..2 {
+            let (one, two) = (rng.pick(&small), rng.pick(&small));
+            if one != two { edges.insert((one.clone(), two.clone())); }
+        }
+
+        for _ in 0..rng.between(1, 2) {
+            edges.insert(("start".to_string(), rng.pick(&caves).to_string()));
+        }
+
+        for _ in 0..rng.between(1, 2) {
+            edges.insert((rng.pick(&caves).to_string(), "end".to_string()));
+        }
+    }
+
+    let mut lines: Vec<String> = edges.into_iter().map(|(one, two)| format!("{}-{}\n", one, two)).collect();
+    rng.shuffle(&mut lines);
+
+    lines.concat()
+}
+
+#[derive(Default)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = Vec<Edge>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, edges: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part_one(edges))
+    }
+
+    fn part_two(&self, edges: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part_two(edges))
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generate(rng, scale))
+    }
+}
+
+#[test]
+fn test_generate() {
+    let one = parse_input(&generate(&mut Rng::new(12), 1)).unwrap();
+    let three = parse_input(&generate(&mut Rng::new(12), 3)).unwrap();
+
+    assert!(three.len() > one.len());
+    assert!(three.iter().all(|edge| matches!((&edge.point_one, &edge.point_two), (Cave::Small(_), _) | (_, Cave::Small(_)))));
+    assert_eq!(cave_name(0), "aa");
+    assert_eq!(cave_name(26 * 26), "aab");
+    part_two(&three);
+}
+
+#[test]
+fn test_parse_error() {
+    let error = Day12.parse("start-A\nA-B\nb-end").err().unwrap();
+    assert_eq!(error.to_string(), "line 2, column 1: expected at least one small cave, found \"A-B\"");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,206 @@
+use aoc_common::trace::NoTrace;
+use aoc_common::parse::integer;
+use aoc_common::{Frame, ParseError, Part, Solution, SolveError, Text, Tracer};
+use itertools::Itertools;
+use std::fmt;
+#[cfg(test)]
+use proptest::{collection::vec, prelude::*};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Point { x: usize, y: usize }
+
+impl Point {
+    fn reflect_over(&mut self, fold: &Fold) {
+        match fold {
+            Fold::X(x) => {
+                if self.x < *x { return; }
+                self.x = 2 * x - self.x;
+            },
+            Fold::Y(y) => {
+                if self.y < *y { return; }
+                self.y = 2 * y - self.y;
+            }
+        }
+    }
+}
+#[derive(Clone, Debug)]
+pub enum Fold { X(usize), Y(usize) }
+
+impl fmt::Display for Fold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fold::X(x) => write!(f, "x={}", x),
+            Fold::Y(y) => write!(f, "y={}", y)
+        }
+    }
+}
+
+fn parse_point(line: Text) -> Result<Point, ParseError> {
+    let (x, y) = line.key_value(",", "a point like 6,10", integer("an x coordinate"), integer("a y coordinate"))?;
+
+    Ok(Point { x, y })
+}
+
+fn parse_fold(line: Text) -> Result<Fold, ParseError> {
+    let (axis, position) = line
+        .strip_prefix("fold along ", "'fold along'")?
+        .key_value("=", "an axis and position like y=7", Ok, integer("a fold position"))?;
+
+    match axis.as_str() {
+        "x" => Ok(Fold::X(position)),
+        "y" => Ok(Fold::Y(position)),
+        _ => Err(axis.error("x or y"))
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Point>, Vec<Fold>), ParseError> {
+    let text = Text::new(input);
+    let mut sections = text.sections();
+
+    let points = sections
+        .next()
+        .ok_or_else(|| text.error_at_end("a point"))?
+        .lines_of(parse_point)?;
+
+    // A fold less than halfway across would leave dots beyond the edge of the paper, so follow
+    // the dots through each fold to check the next one against them
+    let mut folded = points.clone();
+    let mut fold_within_paper = |line: Text| {
+        let fold = parse_fold(line)?;
+        let (position, max) = match fold {
+            Fold::X(x) => (x, folded.iter().map(|p| p.x).max().unwrap_or(0)),
+            Fold::Y(y) => (y, folded.iter().map(|p| p.y).max().unwrap_or(0))
+        };
+
+        match position {
+            0 => Err(line.error("a fold position above 0")),
+            _ if position < max.div_ceil(2) => {
+                Err(line.error(format!("a fold at {} or beyond, to keep every dot on the paper", max.div_ceil(2))))
+            },
+            _ => {
+                folded.iter_mut().for_each(|p| p.reflect_over(&fold));
+                Ok(fold)
+            }
+        }
+    };
+
+    let mut folds = Vec::new();
+    for section in sections { folds.extend(section.lines_of(&mut fold_within_paper)?); }
+
+    if folds.is_empty() { return Err(text.error_at_end("a fold instruction")); }
+
+    Ok((points, folds))
+}
+
+fn render(points: &[Point]) -> String {
+    let x_max = points.iter().map(|p| p.x).max().unwrap();
+    let y_max = points.iter().map(|p| p.y).max().unwrap();
+
+    (0..=y_max)
+        .map(|y| {
+            (0..=x_max)
+                .map(|x| match points.iter().any(|p| p.x == x && p.y == y) {
+                    true => 'X',
+                    false => ' '
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn fold_paper(points: &mut [Point], folds: &[Fold], tracer: &mut dyn Tracer) {
+    tracer.emit(|| Frame::new(format!("Before folding: {} dots", points.iter().unique().count()), render(points)));
+
+    for fold in folds {
+        points.iter_mut().for_each(|p| p.reflect_over(fold));
+        tracer.emit(|| Frame::new(format!("Fold along {}: {} dots", fold, points.iter().unique().count()), render(points)));
+    }
+}
+
+fn part_one(points: &mut [Point], folds: &[Fold], tracer: &mut dyn Tracer) -> usize {
+    fold_paper(points, &folds[..1], tracer);
+
+    points.iter().unique().count()
+}
+
+fn part_two(points: &mut [Point], folds: &[Fold], tracer: &mut dyn Tracer) -> String {
+    fold_paper(points, folds, tracer);
+
+    render(points)
+}
+
+#[derive(Default)]
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = (Vec<Point>, Vec<Fold>);
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<usize, SolveError> {
+        let (points, folds) = input;
+        Ok(part_one(&mut points.clone(), folds, &mut NoTrace))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<String, SolveError> {
+        let (points, folds) = input;
+        Ok(part_two(&mut points.clone(), folds, &mut NoTrace))
+    }
+
+    fn trace(&self, input: &Self::Input, part: Part, tracer: &mut dyn Tracer) -> bool {
+        let (points, folds) = input;
+
+        match part {
+            Part::One => { part_one(&mut points.clone(), folds, tracer); },
+            Part::Two => { part_two(&mut points.clone(), folds, tracer); }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+fn folds() -> impl Strategy<Value = (Vec<(usize, usize)>, Fold)> {
+    (1usize..20, any::<bool>()).prop_flat_map(|(line, along_x)| {
+        let fold = if along_x { Fold::X(line) } else { Fold::Y(line) };
+        (vec((0..=2 * line, 0..=2 * line), 0..30), Just(fold))
+    })
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn prop_folding_twice_changes_nothing((coordinates, fold) in folds()) {
+        let mut points: Vec<Point> = coordinates.iter().map(|&(x, y)| Point { x, y }).collect();
+        points.iter_mut().for_each(|p| p.reflect_over(&fold));
+
+        let folded = points.clone();
+        points.iter_mut().for_each(|p| p.reflect_over(&fold));
+
+        prop_assert!(points == folded);
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = Day13.parse("6,10\n0,14\n\nfold along x=0").err().unwrap();
+    assert_eq!(error.to_string(), "line 4, column 1: expected a fold position above 0, found \"fold along x=0\"");
+
+    let error = Day13.parse("10,0\n\nfold along x=2").err().unwrap();
+    assert_eq!(error.to_string(), "line 3, column 1: expected a fold at 5 or beyond, to keep every dot on the paper, found \"fold along x=2\"");
+
+    // The second fold is checked against the paper the first one left
+    let error = Day13.parse("10,0\n4,8\n\nfold along x=6\nfold along x=1").err().unwrap();
+    assert_eq!(error.to_string(), "line 5, column 1: expected a fold at 2 or beyond, to keep every dot on the paper, found \"fold along x=1\"");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
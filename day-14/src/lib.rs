@@ -0,0 +1,261 @@
+use aoc_common::count::BigUint;
+use aoc_common::{Count, Integer, Overflow, Param, ParseError, Rng, Solution, SolveError, Text};
+use std::collections::HashMap;
+use std::hash::Hash;
+#[cfg(test)]
+use proptest::{collection::vec, prelude::*};
+
+type Rules = HashMap<(char, char), [(char, char); 2]>;
+
+fn parse_pair(pair: Text) -> Result<(char, char), ParseError> {
+    match pair.as_str().chars().collect::<Vec<char>>()[..] {
+        [char_one, char_two] => Ok((char_one, char_two)),
+        _ => Err(pair.error("a pair of elements"))
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<char>, Rules), ParseError> {
+    let text = Text::new(input);
+    let mut sections = text.sections();
+
+    let template = sections
+        .next()
+        .ok_or_else(|| text.error_at_end("a polymer template"))?;
+
+    if let Some(line) = template.lines().nth(1) {
+        return Err(line.error("a blank line"));
+    }
+
+    let mut rules = HashMap::new();
+    let mut lines = Vec::new();
+
+    for line in sections.flat_map(|section| section.lines()) {
+        let ((char_one, char_two), insertion) = line.key_value(
+            " -> ",
+            "a rule like CH -> B",
+            parse_pair,
+            |insertion| insertion.char("a single element")
+        )?;
+
+        let made = [(char_one, insertion), (insertion, char_two)];
+        rules.insert((char_one, char_two), made);
+        lines.push((line, made));
+    }
+
+    // Every pair the template starts with or a rule makes gets split again on the next step
+    let polymer: Vec<char> = template.as_str().chars().collect();
+    if let Some(pair) = polymer.windows(2).map(|pair| (pair[0], pair[1])).find(|pair| !rules.contains_key(pair)) {
+        return Err(template.error(format!("a template with rules for all its pairs, including {}{}", pair.0, pair.1)));
+    }
+
+    for (line, made) in lines {
+        if let Some(pair) = made.iter().find(|pair| !rules.contains_key(pair)) {
+            return Err(line.error(format!("a rule that makes pairs with rules, including {}{}", pair.0, pair.1)));
+        }
+    }
+
+    Ok((polymer, rules))
+}
+
+fn increment<K: Eq + Hash, N: Count>(map: &mut HashMap<K, N>, key: K, n: &N) -> Result<(), Overflow> {
+    let new_count = match map.get(&key) {
+        Some(c) => c.add(n)?,
+        None => n.clone()
+    };
+
+    map.insert(key, new_count);
+    Ok(())
+}
+
+fn iterate_and_diff<N: Count>(polymer: &[char], rules: &Rules, count: usize) -> Result<N, Overflow> {
+    let one = N::from_u64(1);
+    let mut counts: HashMap<(char, char), N> = HashMap::new();
+
+    // Load the counts
+    for i in 0..polymer.len() - 1 {
+        let pair = (polymer[i], polymer[i + 1]);
+        increment(&mut counts, pair, &one)?;
+    }
+
+    // Iterate the counts
+    for _ in 0..count {
+        let mut next_counts: HashMap<(char, char), N> = HashMap::new();
+
+        for (key_pair, count) in counts {
+            let [first_next_pair, second_next_pair] = rules.get(&key_pair).unwrap();
+            increment(&mut next_counts, *first_next_pair, &count)?;
+            increment(&mut next_counts, *second_next_pair, &count)?;
+        }
+
+        counts = next_counts;
+    }
+
+    // Sum everything
+    let mut letter_counts = HashMap::new();
+
+    for ((l1, l2), count) in &counts {
+        increment(&mut letter_counts, l1, count)?;
+        increment(&mut letter_counts, l2, count)?;
+    }
+
+    // Add in the first and last letters since all things are doubled but them
+    increment(&mut letter_counts, polymer.first().unwrap(), &one)?;
+    increment(&mut letter_counts, polymer.last().unwrap(), &one)?;
+
+    // Find the min and max
+    let max = letter_counts
+        .values()
+        .max()
+        .unwrap();
+
+    let min = letter_counts
+        .values()
+        .min()
+        .unwrap();
+
+    Ok(max.sub(min).div(2))
+}
+
+/// Makes up a template `20 * scale` elements long, using ten elements with a rule for every
+/// pair of them.
+fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut alphabet: Vec<char> = ('A'..='Z').collect();
+    rng.shuffle(&mut alphabet);
+    let elements = &alphabet[..10];
+
+    let mut output: String = (0..20 * scale).map(|_| *rng.pick(elements)).collect();
+    output.push_str("\n\n");
+
+    for one in elements {
+        for two in elements {
+            output += &format!("{}{} -> {}\n", one, two, rng.pick(elements));
+        }
+    }
+
+    output
+}
+
+pub struct Day14 {
+    /// Steps of pair insertion to run in each part.
+    pub part_one_steps: usize,
+    pub part_two_steps: usize,
+    /// What to count elements with, since they grow exponentially with the steps.
+    pub integer: Integer
+}
+
+impl Day14 {
+    fn count(&self, (polymer, rules): &(Vec<char>, Rules), steps: usize) -> Result<String, SolveError> {
+        let count = match self.integer {
+            Integer::U64 => iterate_and_diff::<u64>(polymer, rules, steps)?.to_string(),
+            Integer::U128 => iterate_and_diff::<u128>(polymer, rules, steps)?.to_string(),
+            Integer::Big => iterate_and_diff::<BigUint>(polymer, rules, steps)?.to_string()
+        };
+
+        Ok(count)
+    }
+}
+
+impl Default for Day14 {
+    fn default() -> Day14 {
+        Day14 { part_one_steps: 10, part_two_steps: 40, integer: Integer::U64 }
+    }
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = (Vec<char>, Rules);
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<String, SolveError> {
+        self.count(input, self.part_one_steps)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<String, SolveError> {
+        self.count(input, self.part_two_steps)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generate(rng, scale))
+    }
+
+    fn params(&mut self) -> Vec<Param<'_>> {
+        vec!(
+            Param::new("part-one-steps", &mut self.part_one_steps),
+            Param::new("part-two-steps", &mut self.part_two_steps),
+            Param::new("integer", &mut self.integer)
+        )
+    }
+}
+
+#[cfg(test)]
+fn expand_and_diff(polymer: &[char], rules: &Rules, count: usize) -> u64 {
+    let mut polymer = polymer.to_vec();
+
+    for _ in 0..count {
+        let mut next = vec!(polymer[0]);
+
+        for pair in polymer.windows(2) {
+            let [(_, insertion), _] = rules[&(pair[0], pair[1])];
+            next.extend([insertion, pair[1]]);
+        }
+
+        polymer = next;
+    }
+
+    let mut letter_counts = HashMap::new();
+    polymer.iter().for_each(|&letter| increment(&mut letter_counts, letter, &1).unwrap());
+
+    letter_counts.values().max().unwrap() - letter_counts.values().min().unwrap()
+}
+
+#[cfg(test)]
+fn elements() -> impl Strategy<Value = char> {
+    prop_oneof![Just('B'), Just('C'), Just('H')]
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn prop_pair_counts_match_expansion(
+        polymer in vec(elements(), 1..8),
+        insertions in vec(elements(), 9),
+        count in 0usize..8
+    ) {
+        let pairs = ['B', 'C', 'H'].iter().flat_map(|&a| ['B', 'C', 'H'].iter().map(move |&b| (a, b)));
+        let rules: Rules = pairs
+            .zip(insertions)
+            .map(|((a, b), insertion)| ((a, b), [(a, insertion), (insertion, b)]))
+            .collect();
+
+        prop_assert_eq!(iterate_and_diff(&polymer, &rules, count), Ok(expand_and_diff(&polymer, &rules, count)));
+    }
+}
+
+#[test]
+fn test_generate() {
+    let (template, rules) = parse_input(&generate(&mut Rng::new(14), 3)).unwrap();
+
+    assert_eq!((template.len(), rules.len()), (60, 100));
+    assert!(iterate_and_diff::<u64>(&template, &rules, 40).unwrap() > 0);
+    assert!(iterate_and_diff::<u64>(&template, &rules, 100).is_err());
+    assert!(iterate_and_diff::<BigUint>(&template, &rules, 100).is_ok());
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = Day14::default().parse("NN\n\nNN -> C\nNC -> N").err().unwrap();
+    assert_eq!(error.to_string(), "line 3, column 1: expected a rule that makes pairs with rules, including CN, found \"NN -> C\"");
+
+    let error = Day14::default().parse("NNC\n\nNN -> N").err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 1: expected a template with rules for all its pairs, including NC, found \"NNC\"");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,92 @@
+use aoc_common::{search, Grid, Param, ParseError, Solution, SolveError};
+use std::num::NonZeroUsize;
+
+/// Wraps a risk level raised past 9 back round to 1, however many times it goes past.
+fn rot(i: usize) -> usize {
+    (i - 1) % 9 + 1
+}
+
+fn extend_map(map: &Grid<u8>, tiles: usize) -> Grid<u8> {
+    let (width, height) = (map.width(), map.height());
+
+    // Tile to the right and down, each copy one step riskier than the last
+    Grid::from_fn(width * tiles, height * tiles, |x, y| {
+        let copy = x / width + y / height;
+        rot(map[(x % width, y % height)] as usize + copy) as u8
+    })
+}
+
+fn part_one(map: &Grid<u8>) -> usize {
+    shortest_path_cost(map)
+}
+
+fn part_two(map: &Grid<u8>, tiles: usize) -> usize {
+    shortest_path_cost(&extend_map(map, tiles))
+}
+
+fn shortest_path_cost(map: &Grid<u8>) -> usize {
+    let destination = (map.width() - 1, map.height() - 1);
+
+    search::dijkstra(map, (0, 0), |&position| position == destination)
+        .expect("Path not found")
+        .cost
+}
+
+pub struct Day15 {
+    /// How many times across and down the map is tiled in part two.
+    pub tiles: NonZeroUsize
+}
+
+impl Default for Day15 {
+    fn default() -> Day15 {
+        Day15 { tiles: NonZeroUsize::new(5).unwrap() }
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = Grid<u8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, "a risk level from 1 to 9", |c| c.to_digit(10).filter(|&d| d > 0).map(|d| d as u8))
+    }
+
+    fn part_one(&self, map: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part_one(map))
+    }
+
+    fn part_two(&self, map: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part_two(map, self.tiles.get()))
+    }
+
+    fn params(&mut self) -> Vec<Param<'_>> {
+        vec!(Param::new("tiles", &mut self.tiles))
+    }
+}
+
+#[test]
+fn test_non_square_grid() {
+    let map = Day15::default().parse("19\n11\n11\n").unwrap();
+    assert_eq!(part_one(&map), 3);
+    assert_eq!(part_one(&Day15::default().parse("5").unwrap()), 0);
+}
+
+#[test]
+fn test_many_tiles() {
+    // Past five tiles the risk wraps round more than once
+    let map = Day15::default().parse("9").unwrap();
+    assert_eq!(part_two(&map, 6), 46);
+}
+
+#[test]
+fn test_parse_error() {
+    let error = Day15::default().parse("10").err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 2: expected a risk level from 1 to 9, found \"0\"");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,397 @@
+use aoc_common::{ParseError, Rng, Solution, SolveError, Text};
+#[cfg(test)]
+use proptest::{collection::vec, prelude::*};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Packet {
+    Operator {
+        version: usize,
+        packet_type: usize,
+        length_type_id: usize,
+        contents: Vec<Packet>
+    },
+    Literal {
+        version: usize,
+        literal: usize,
+        length: usize
+    }
+}
+
+impl Packet {
+    fn write_binary(&self, binary: &mut Vec<usize>) {
+        match self {
+            Packet::Literal { version, literal, length } => {
+                binary.extend(int_to_binary(*version, 3));
+                binary.extend(int_to_binary(4, 3));
+
+                // Groups of four bits, most significant first, each flagged if more follow
+                for group in (0..length / 5).rev() {
+                    binary.push(if group > 0 { 1 } else { 0 });
+                    binary.extend(int_to_binary(literal >> (4 * group), 4));
+                }
+            },
+            Packet::Operator { version, packet_type, length_type_id, contents } => {
+                let mut contents_binary = Vec::new();
+                contents.iter().for_each(|packet| packet.write_binary(&mut contents_binary));
+
+                binary.extend(int_to_binary(*version, 3));
+                binary.extend(int_to_binary(*packet_type, 3));
+                binary.push(*length_type_id);
+
+                match length_type_id {
+                    0 => binary.extend(int_to_binary(contents_binary.len(), 15)),
+                    _ => binary.extend(int_to_binary(contents.len(), 11))
+                }
+
+                binary.extend(contents_binary);
+            }
+        }
+    }
+
+    /// Encodes the packet as the hexadecimal transmission it would be decoded from, padded
+    /// with zero bits to a whole number of digits.
+    pub fn to_hex(&self) -> String {
+        let mut binary = Vec::new();
+        self.write_binary(&mut binary);
+        binary.resize(binary.len().div_ceil(4) * 4, 0);
+
+        binary
+            .chunks(4)
+            .map(|digit| char::from_digit(binary_to_int(digit) as u32, 16).unwrap().to_ascii_uppercase())
+            .collect()
+    }
+}
+
+fn decode_hex(hex: char) -> Option<Vec<usize>> {
+    match hex {
+        'F' => Some(vec!(1, 1, 1, 1)),
+        'E' => Some(vec!(1, 1, 1, 0)),
+        'D' => Some(vec!(1, 1, 0, 1)),
+        'C' => Some(vec!(1, 1, 0, 0)),
+        'B' => Some(vec!(1, 0, 1, 1)),
+        'A' => Some(vec!(1, 0, 1, 0)),
+        '9' => Some(vec!(1, 0, 0, 1)),
+        '8' => Some(vec!(1, 0, 0, 0)),
+        '7' => Some(vec!(0, 1, 1, 1)),
+        '6' => Some(vec!(0, 1, 1, 0)),
+        '5' => Some(vec!(0, 1, 0, 1)),
+        '4' => Some(vec!(0, 1, 0, 0)),
+        '3' => Some(vec!(0, 0, 1, 1)),
+        '2' => Some(vec!(0, 0, 1, 0)),
+        '1' => Some(vec!(0, 0, 0, 1)),
+        '0' => Some(vec!(0, 0, 0, 0)),
+        _ => None
+    }
+}
+
+/// Operators nested deeper than this are rejected rather than risking the stack.
+const MAX_DEPTH: usize = 200;
+
+/// The bits of a transmission, read from the front, with errors pointing at the hexadecimal
+/// digit the bad bits came from.
+struct Bits<'a> {
+    input: &'a str,
+    hex: &'a str,
+    bits: Vec<usize>,
+    position: usize
+}
+
+impl<'a> Bits<'a> {
+    fn error(&self, bit: usize, expected: &str) -> ParseError {
+        let digit = bit / 4;
+
+        match digit < self.hex.len() {
+            true => ParseError::at(self.input, &self.hex[digit..digit + 1], expected),
+            false => ParseError::at(self.input, &self.hex[self.hex.len()..], expected).found("the end of the packet")
+        }
+    }
+
+    fn read(&mut self, count: usize, expected: &str) -> Result<usize, ParseError> {
+        let bits = self.bits
+            .get(self.position..self.position + count)
+            .ok_or_else(|| self.error(self.bits.len(), expected))?;
+
+        self.position += count;
+        Ok(binary_to_int(bits))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Packet, ParseError> {
+    let text = Text::new(input);
+    let hex_string = text
+        .lines()
+        .next()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .ok_or_else(|| text.error_at_end("a hexadecimal packet"))?;
+
+    hex_to_packet(input, hex_string.as_str())
+}
+
+/// Decodes the packet in `hex`, a slice of `input`.
+fn hex_to_packet(input: &str, hex: &str) -> Result<Packet, ParseError> {
+    let mut binary: Vec<usize> = Vec::new();
+
+    for (i, hex_char) in hex.char_indices() {
+        let bits = decode_hex(hex_char)
+            .ok_or_else(|| ParseError::at(input, &hex[i..i + hex_char.len_utf8()], "a hexadecimal digit"))?;
+
+        binary.extend(bits);
+    }
+
+    read_packet(&mut Bits { input, hex, bits: binary, position: 0 }, 0)
+}
+
+fn read_packet(bits: &mut Bits, depth: usize) -> Result<Packet, ParseError> {
+    let start = bits.position;
+
+    if depth > MAX_DEPTH {
+        return Err(bits.error(start, &format!("packets nested at most {} deep", MAX_DEPTH)));
+    }
+
+    let version = bits.read(3, "a packet version")?;
+
+    match bits.read(3, "a packet type")? {
+        4 => {
+            let (literal, length) = read_literal(bits)?;
+            Ok(Packet::Literal { version, literal, length })
+        },
+        packet_type => {
+            let length_type_id = bits.read(1, "a length type")?;
+            let mut contents = Vec::new();
+
+            match length_type_id {
+                0 => {
+                    let total_bit_length = bits.read(15, "a sub-packet length")?;
+                    let end = bits.position + total_bit_length;
+
+                    while bits.position < end {
+                        contents.push(read_packet(bits, depth + 1)?);
+                    }
+
+                    if bits.position > end {
+                        return Err(bits.error(start, "sub-packets that fit the declared length"));
+                    }
+                },
+                _ => {
+                    let packet_count = bits.read(11, "a sub-packet count")?;
+
+                    for _ in 0..packet_count {
+                        contents.push(read_packet(bits, depth + 1)?);
+                    }
+                }
+            };
+
+            match (packet_type, contents.len()) {
+                (5..=7, 2) => (),
+                (5..=7, _) => return Err(bits.error(start, "a comparison of two sub-packets")),
+                (_, 0) => return Err(bits.error(start, "an operator with at least one sub-packet")),
+                _ => ()
+            }
+
+            Ok(Packet::Operator { version, length_type_id, contents, packet_type })
+        }
+    }
+}
+
+/// Reads the groups of a literal value, returning it with the number of bits they took.
+fn read_literal(bits: &mut Bits) -> Result<(usize, usize), ParseError> {
+    let start = bits.position;
+    let mut output: usize = 0;
+
+    loop {
+        let more = bits.read(1, "a literal group")?;
+
+        if output.leading_zeros() < 4 {
+            return Err(bits.error(start, "a literal that fits in 64 bits"));
+        }
+
+        output = (output << 4) + bits.read(4, "a literal group")?;
+
+        if more == 0 {
+            return Ok((output, bits.position - start));
+        }
+    }
+}
+
+fn int_to_binary(value: usize, bits: usize) -> impl Iterator<Item = usize> {
+    (0..bits).rev().map(move |bit| (value >> bit) & 1)
+}
+
+fn binary_to_int(binary: &[usize]) -> usize {
+    binary.iter().fold(0, |acc, x| (acc << 1) + x)
+}
+
+fn sum_versions(packet: &Packet) -> usize {
+    match packet {
+        Packet::Operator { version, contents, .. } =>
+            *version + contents.iter().map(sum_versions).sum::<usize>(),
+        Packet::Literal { version, .. } => *version
+    }
+}
+
+/// The value of a packet, or `None` if a sum or product along the way overflows.
+fn compute_packets(packet: &Packet) -> Option<usize> {
+    match packet {
+        Packet::Operator { packet_type, contents, .. } => {
+            let values = contents.iter().map(compute_packets).collect::<Option<Vec<usize>>>()?;
+
+            match packet_type {
+                0 => values.iter().try_fold(0_usize, |acc, &value| acc.checked_add(value)),
+                1 => values.iter().try_fold(1_usize, |acc, &value| acc.checked_mul(value)),
+                2 => values.iter().min().copied(),
+                3 => values.iter().max().copied(),
+                5 => Some(if values[0] > values[1] { 1 } else { 0 }),
+                6 => Some(if values[0] < values[1] { 1 } else { 0 }),
+                7 => Some(if values[0] == values[1] { 1 } else { 0 }),
+                _ => panic!()
+            }
+        },
+        Packet::Literal { literal, .. } => Some(*literal)
+    }
+}
+
+fn literal(version: usize, literal: usize) -> Packet {
+    let groups = (usize::BITS - literal.leading_zeros()).div_ceil(4).max(1) as usize;
+    Packet::Literal { version, literal, length: groups * 5 }
+}
+
+fn operator(rng: &mut Rng, version: usize, packet_type: usize, contents: Vec<Packet>) -> Packet {
+    let mut binary = Vec::new();
+    contents.iter().for_each(|packet| packet.write_binary(&mut binary));
+
+    // A bit length has to fit in 15 bits, but a count of up to four sub-packets always fits
+    let length_type_id = if binary.len() >= 1 << 15 { 1 } else { rng.below(2) };
+
+    Packet::Operator { version, packet_type, length_type_id, contents }
+}
+
+/// Makes up a packet made of about `size` packets. Products only ever multiply a few small
+/// literals near the leaves, so the value stays far from overflowing.
+fn random_packet(rng: &mut Rng, size: usize) -> Packet {
+    let version = rng.below(8);
+
+    if size <= 3 {
+        return literal(version, rng.below(1000));
+    }
+
+    match rng.below(6) {
+        0 if size <= 8 => {
+            let factors = (0..rng.between(2, 3)).map(|_| literal(rng.below(8), rng.below(1000))).collect();
+            operator(rng, version, 1, factors)
+        },
+        1 => {
+            let comparison = rng.between(5, 7);
+            let pair = vec!(random_packet(rng, (size - 1) / 2), random_packet(rng, (size - 1) / 2));
+            operator(rng, version, comparison, pair)
+        },
+        _ => {
+            let (packet_type, count) = (*rng.pick(&[0, 2, 3]), rng.between(2, 4));
+            let contents = (0..count).map(|i| random_packet(rng, (size - 1 + i) / count)).collect();
+            operator(rng, version, packet_type, contents)
+        }
+    }
+}
+
+/// Makes up a transmission of about `250 * scale` packets.
+fn generate(rng: &mut Rng, scale: usize) -> String {
+    random_packet(rng, 250 * scale).to_hex() + "\n"
+}
+
+#[derive(Default)]
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = Packet;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, packet: &Self::Input) -> Result<usize, SolveError> {
+        Ok(sum_versions(packet))
+    }
+
+    fn part_two(&self, packet: &Self::Input) -> Result<usize, SolveError> {
+        compute_packets(packet).ok_or_else(|| SolveError::no_answer("the value of the transmission is too large to compute"))
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generate(rng, scale))
+    }
+}
+
+#[cfg(test)]
+fn packets() -> impl Strategy<Value = Packet> {
+    let literal = (0usize..8, any::<u64>()).prop_map(|(version, literal)| {
+        let groups = (64 - literal.leading_zeros() as usize).div_ceil(4).max(1);
+        Packet::Literal { version, literal: literal as usize, length: groups * 5 }
+    });
+
+    literal.prop_recursive(4, 32, 4, |packet| {
+        (0usize..8, 0usize..7, 0usize..2, vec(packet, 1..5)).prop_map(|(version, kind, length_type_id, contents)| {
+            // Comparisons need exactly two sub-packets, so fall back to another operator
+            let packet_type = match (kind, contents.len()) {
+                (4..=6, 2) => kind + 1,
+                (kind, _) => kind % 4
+            };
+
+            Packet::Operator { version, packet_type, length_type_id, contents }
+        })
+    })
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn prop_encoding_round_trips(packet in packets()) {
+        prop_assert_eq!(hex_to_packet(&packet.to_hex(), &packet.to_hex()).unwrap(), packet);
+    }
+}
+
+#[test]
+fn test_to_hex() {
+    // Transmissions may carry more zero padding than the encoder adds
+    for hex in ["D2FE28", "38006F45291200", "EE00D40C823060", "8A004A801A8002F478"] {
+        assert!(hex.starts_with(&hex_to_packet(hex, hex).unwrap().to_hex()));
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = Day16.parse("D2FE2\n").err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 6: expected a literal group, found the end of the packet");
+
+    let error = Day16.parse("D800").err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 5: expected a sub-packet length, found the end of the packet");
+
+    let literal = Packet::Literal { version: 0, literal: 1, length: 5 };
+    let comparison = Packet::Operator { version: 0, packet_type: 6, length_type_id: 1, contents: vec!(literal) };
+    let error = Day16.parse(&comparison.to_hex()).err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 1: expected a comparison of two sub-packets, found \"1\"");
+}
+
+#[test]
+fn test_generate() {
+    for seed in 0..20 {
+        let packet = parse_input(&generate(&mut Rng::new(seed), 1)).unwrap();
+        assert!(compute_packets(&packet).is_some());
+    }
+}
+
+#[test]
+fn test_overflow() {
+    let large = Packet::Literal { version: 0, literal: 1 << 40, length: 55 };
+    let product = Packet::Operator { version: 0, packet_type: 1, length_type_id: 1, contents: vec!(large.clone(), large) };
+    let packet = Day16.parse(&product.to_hex()).unwrap();
+
+    assert_eq!(Day16.part_two(&packet), Err(SolveError::no_answer("the value of the transmission is too large to compute")));
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
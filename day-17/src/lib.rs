@@ -0,0 +1,246 @@
+use aoc_common::parse::integer;
+use aoc_common::{Frame, ParseError, Part, Solution, SolveError, Text, Tracer};
+
+pub struct TargetArea { xmin: i32, xmax: i32, ymin: i32, ymax: i32 }
+struct Probe { x: i32, y: i32, xvel: i32, yvel: i32 }
+
+impl TargetArea {
+    fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.xmin && x <= self.xmax && y >= self.ymin && y <= self.ymax
+    }
+}
+
+/// How far from the launcher the target may be, so the probe's path stays small enough to simulate.
+const MAX_DISTANCE: i32 = 1000;
+
+fn parse_range(range: Text, axis: &str) -> Result<(i32, i32), ParseError> {
+    let (low, high) = range
+        .strip_prefix(&format!("{}=", axis), &format!("a range for {}", axis))?
+        .key_value("..", "a range like 20..30", integer("a lower bound"), integer("an upper bound"))?;
+
+    match low <= high {
+        true => Ok((low, high)),
+        false => Err(range.error("a range from a lower to an upper bound"))
+    }
+}
+
+/// The target has to be ahead of the launcher, which the search for shots relies on.
+fn parse_x_range(x: Text) -> Result<(i32, i32), ParseError> {
+    match parse_range(x, "x")? {
+        (xmin, xmax) if xmin > 0 && xmax <= MAX_DISTANCE => Ok((xmin, xmax)),
+        _ => Err(x.error(format!("an x range ahead of the launcher, up to {}", MAX_DISTANCE)))
+    }
+}
+
+/// The target has to be below the launcher, which the search for shots relies on.
+fn parse_y_range(y: Text) -> Result<(i32, i32), ParseError> {
+    match parse_range(y, "y")? {
+        (ymin, ymax) if ymax < 0 && ymin >= -MAX_DISTANCE => Ok((ymin, ymax)),
+        _ => Err(y.error(format!("a y range below the launcher, down to -{}", MAX_DISTANCE)))
+    }
+}
+
+fn parse_input(input: &str) -> Result<TargetArea, ParseError> {
+    let ((xmin, xmax), (ymin, ymax)) = Text::new(input)
+        .trim()
+        .strip_prefix("target area: ", "'target area: '")?
+        .key_value(", ", "x and y ranges separated by ', '", parse_x_range, parse_y_range)?;
+
+    Ok(TargetArea { xmin, xmax, ymin, ymax })
+}
+
+fn step(probe: &mut Probe) {
+    probe.x += probe.xvel;
+    probe.y += probe.yvel;
+    probe.yvel -= 1;
+    if probe.xvel > 0 { probe.xvel -= 1 } else if probe.xvel < 0 { probe.xvel += 1 };
+}
+
+fn probe_will_hit(probe: &mut Probe, target: &TargetArea) -> Option<i32> {
+    let mut y_peak = probe.y;
+
+    for _ in 0..1000 {
+        step(probe);
+        if probe.y > y_peak { y_peak = probe.y };
+
+        if target.contains(probe.x, probe.y) {
+            return Some(y_peak);
+        }
+
+        // Once it passes or falls below the target it can never come back to it
+        if probe.x > target.xmax || (probe.y < target.ymin && probe.yvel < 0) { break; }
+    }
+
+    None
+}
+
+/// The starting velocity that sends the probe highest while still hitting the target.
+fn highest_shot(target: &TargetArea) -> Option<(i32, i32)> {
+    (0..=target.xmax)
+        .flat_map(|x| (target.ymin..1000).map(move |y| (x, y)))
+        .filter_map(|(x, y)| probe_will_hit(&mut Probe { x: 0, y: 0, xvel: x, yvel: y }, target).map(|peak| ((x, y), peak)))
+        .max_by_key(|&(_, peak)| peak)
+        .map(|(velocity, _)| velocity)
+}
+
+fn part_one(target: &TargetArea) -> Option<i32> {
+    let (xvel, yvel) = highest_shot(target)?;
+    probe_will_hit(&mut Probe { x: 0, y: 0, xvel, yvel }, target)
+}
+
+/// Draws the trajectory so far the way the puzzle does, unless it is too big to be useful.
+fn draw(target: &TargetArea, path: &[(i32, i32)]) -> String {
+    let xmax = path.iter().map(|&(x, _)| x).max().unwrap().max(target.xmax);
+    let ymin = path.iter().map(|&(_, y)| y).min().unwrap().min(target.ymin);
+    let ymax = path.iter().map(|&(_, y)| y).max().unwrap();
+
+    if xmax > 250 || ymax - ymin > 120 {
+        return "(too large to draw)".to_string();
+    }
+
+    (ymin..=ymax)
+        .rev()
+        .map(|y| {
+            (0..=xmax)
+                .map(|x| match (x, y) {
+                    (0, 0) => 'S',
+                    _ if path.contains(&(x, y)) => '#',
+                    _ if target.contains(x, y) => 'T',
+                    _ => '.'
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn trace_shot(target: &TargetArea, xvel: i32, yvel: i32, tracer: &mut dyn Tracer) {
+    let mut probe = Probe { x: 0, y: 0, xvel, yvel };
+    let mut path = vec!((0, 0));
+
+    tracer.emit(|| Frame::new(format!("Launch at velocity {},{}", xvel, yvel), draw(target, &path)));
+
+    for number in 1..=1000 {
+        step(&mut probe);
+        path.push((probe.x, probe.y));
+
+        let hit = target.contains(probe.x, probe.y);
+        let label = format!("Step {}: at {},{}{}", number, probe.x, probe.y, if hit { ", hit" } else { "" });
+        tracer.emit(|| Frame::new(label, draw(target, &path)));
+
+        if hit || (probe.y < target.ymin && probe.yvel < 0) { break; }
+    }
+}
+
+fn part_two(target: &TargetArea) -> i32 {
+    let mut hits = 0;
+
+    for x in 0..=target.xmax {
+        for y in target.ymin..1000 {
+            if probe_will_hit(&mut Probe { x: 0, y: 0, xvel: x, yvel: y }, target).is_some() {
+                hits += 1;
+            }
+        }
+    }
+
+    hits
+}
+
+#[derive(Default)]
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = TargetArea;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, target: &Self::Input) -> Result<i32, SolveError> {
+        part_one(target).ok_or_else(|| SolveError::no_answer("no launch velocity hits the target"))
+    }
+
+    fn part_two(&self, target: &Self::Input) -> Result<i32, SolveError> {
+        Ok(part_two(target))
+    }
+
+    fn trace(&self, target: &Self::Input, part: Part, tracer: &mut dyn Tracer) -> bool {
+        match part {
+            Part::One => match highest_shot(target) {
+                Some((xvel, yvel)) => {
+                    trace_shot(target, xvel, yvel, tracer);
+                    true
+                },
+                None => false
+            },
+            Part::Two => false
+        }
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let target = TargetArea { xmin: 20, xmax: 30, ymin: -10, ymax: -5 };
+    let mut probe_one = Probe { x: 0, y: 0, xvel: 6, yvel: 3 };
+    assert!(probe_will_hit(&mut probe_one, &target).is_some());
+
+    let mut probe_two = Probe { x: 0, y: 0, xvel: 17, yvel: -4 };
+    assert_eq!(probe_will_hit(&mut probe_two, &target), None);
+
+    let mut probe_three = Probe { x: 0, y: 0, xvel: 6, yvel: 9 };
+    assert_eq!(probe_will_hit(&mut probe_three, &target), Some(45));
+    assert_eq!(part_one(&target), Some(45));
+}
+
+#[test]
+fn test_draw() {
+    let target = TargetArea { xmin: 20, xmax: 30, ymin: -10, ymax: -5 };
+    let path = [(0, 0), (7, 2), (13, 3), (18, 3), (22, 2), (25, 0), (27, -3), (28, -7)];
+    let expected = [
+        ".............#....#............",
+        ".......#..............#........",
+        "...............................",
+        "S........................#.....",
+        "...............................",
+        "...............................",
+        "...........................#...",
+        "...............................",
+        "....................TTTTTTTTTTT",
+        "....................TTTTTTTTTTT",
+        "....................TTTTTTTT#TT",
+        "....................TTTTTTTTTTT",
+        "....................TTTTTTTTTTT",
+        "....................TTTTTTTTTTT"
+    ];
+
+    assert_eq!(draw(&target, &path), expected.join("\n"));
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = Day17.parse("target area: x=30..20, y=-10..-5").err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 14: expected a range from a lower to an upper bound, found \"x=30..20\"");
+
+    let error = Day17.parse("target area: x=-30..-20, y=-10..-5").err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 14: expected an x range ahead of the launcher, up to 1000, found \"x=-30..-20\"");
+
+    let error = Day17.parse("target area: x=20..30, y=5..10").err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 24: expected a y range below the launcher, down to -1000, found \"y=5..10\"");
+}
+
+#[test]
+fn test_low_shots_only() {
+    // No horizontal velocity leaves the probe falling straight down into the target, so the
+    // shots that hit are all thrown downwards and never get above the launcher
+    let target = parse_input("target area: x=4..5, y=-10..-5").unwrap();
+    assert_eq!(part_one(&target), Some(0));
+    assert_eq!(part_two(&target), 15);
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,337 @@
+use aoc_common::{ParseError, Solution, SolveError, Text};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Forward(i64),
+    Back(i64),
+    Up(i64),
+    Down(i64)
+}
+
+/// One step of a navigation script, either a single instruction or a block of steps to go
+/// through a number of times.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    Move(Instruction),
+    Repeat(usize, Vec<Command>)
+}
+
+/// How deep repeat blocks can be nested, which keeps the parser from running out of stack.
+const MAX_NESTING: usize = 64;
+
+/// How many instructions a script can expand to once every repeat is unrolled, which keeps
+/// following it quick. Each time round an empty repeat block counts as one.
+const MAX_STEPS: usize = 10_000_000;
+
+/// The words of one script line, read from left to right.
+struct Line<'a> {
+    words: Vec<Text<'a>>,
+    next: usize
+}
+
+impl<'a> Line<'a> {
+    fn word(&mut self, expected: &str) -> Result<Text<'a>, ParseError> {
+        match self.words.get(self.next) {
+            Some(&word) => {
+                self.next += 1;
+                Ok(word)
+            },
+            None => Err(self.words[self.next - 1].end().error(expected))
+        }
+    }
+
+    fn finish(&self) -> Result<(), ParseError> {
+        match self.words.get(self.next) {
+            Some(word) => Err(word.error("the end of the line")),
+            None => Ok(())
+        }
+    }
+}
+
+/// Splits a script into the words of each line, dropping `#` comments and any lines left
+/// with no words at all.
+fn tokenize(text: Text) -> impl Iterator<Item = Line> {
+    text.lines()
+        .map(|line| line.split("#").next().unwrap_or(line).split_whitespace().collect::<Vec<Text>>())
+        .filter(|words| !words.is_empty())
+        .map(|words| Line { words, next: 0 })
+}
+
+/// Reads commands until the end of the input, or until the `}` closing the repeat block the
+/// commands are in when `depth` is more than 0. Also gives the number of instructions the
+/// commands expand to.
+fn parse_block<'a, L>(lines: &mut L, text: Text<'a>, depth: usize) -> Result<(Vec<Command>, usize), ParseError>
+where
+    L: Iterator<Item = Line<'a>>
+{
+    let mut commands = Vec::new();
+    let mut steps: usize = 0;
+
+    while let Some(mut line) = lines.next() {
+        let keyword = line.word("a command")?;
+
+        let command = match keyword.as_str() {
+            "}" if depth > 0 => {
+                line.finish()?;
+                return Ok((commands, steps));
+            },
+            "repeat" if depth == MAX_NESTING => {
+                return Err(keyword.error(format!("no more than {} nested repeats", MAX_NESTING)));
+            },
+            "repeat" => {
+                let times = line.word("a repeat count")?.parse("a repeat count")?;
+                let brace = line.word("{")?;
+                if brace.as_str() != "{" { return Err(brace.error("{")); }
+                line.finish()?;
+
+                let (body, body_steps) = parse_block(lines, text, depth + 1)?;
+                steps = body_steps
+                    .max(1)
+                    .checked_mul(times)
+                    .and_then(|repeated| steps.checked_add(repeated))
+                    .unwrap_or(usize::MAX);
+
+                Command::Repeat(times, body)
+            },
+            _ => {
+                let instruction = match keyword.as_str() {
+                    "forward" => Instruction::Forward,
+                    "back" => Instruction::Back,
+                    "up" => Instruction::Up,
+                    "down" => Instruction::Down,
+                    _ => return Err(keyword.error("forward, back, up, down or repeat"))
+                };
+
+                let amount = line.word("an amount")?.parse("an amount")?;
+                line.finish()?;
+                steps += 1;
+
+                Command::Move(instruction(amount))
+            }
+        };
+
+        if steps > MAX_STEPS {
+            return Err(keyword.error(format!("no more than {} instructions once repeats are unrolled", MAX_STEPS)));
+        }
+
+        commands.push(command);
+    }
+
+    match depth {
+        0 => Ok((commands, steps)),
+        _ => Err(text.error_at_end("} to close a repeat"))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    let text = Text::new(input);
+    let (commands, _) = parse_block(&mut tokenize(text), text, 0)?;
+
+    Ok(commands)
+}
+
+/// How far along and how deep a submarine is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Position {
+    pub x: i64,
+    pub z: i64
+}
+
+impl Position {
+    pub fn product(&self) -> Result<i64, SolveError> {
+        self.x.checked_mul(self.z).ok_or_else(too_far)
+    }
+}
+
+fn too_far() -> SolveError {
+    SolveError::no_answer("the submarine goes further than a 64-bit integer can hold")
+}
+
+/// How instructions move a submarine. `State` is anything the model keeps track of besides
+/// the position, like the aim. Stepping gives `None` if the position or state overflows.
+pub trait MovementModel {
+    type State: Default;
+
+    fn step(&self, instruction: &Instruction, position: &mut Position, state: &mut Self::State) -> Option<()>;
+}
+
+/// Part one's reading, where up and down change the depth directly.
+pub struct Direct;
+
+impl MovementModel for Direct {
+    type State = ();
+
+    fn step(&self, instruction: &Instruction, position: &mut Position, _state: &mut ()) -> Option<()> {
+        match instruction {
+            Instruction::Forward(amount) => position.x = position.x.checked_add(*amount)?,
+            Instruction::Back(amount) => position.x = position.x.checked_sub(*amount)?,
+            Instruction::Up(amount) => position.z = position.z.checked_sub(*amount)?,
+            Instruction::Down(amount) => position.z = position.z.checked_add(*amount)?
+        }
+
+        Some(())
+    }
+}
+
+/// Part two's reading, where up and down turn the submarine and moving forward changes the
+/// depth by the aim. Going back retraces a move forward. The state is the aim.
+pub struct Aim;
+
+impl MovementModel for Aim {
+    type State = i64;
+
+    fn step(&self, instruction: &Instruction, position: &mut Position, aim: &mut i64) -> Option<()> {
+        match instruction {
+            Instruction::Forward(amount) => {
+                position.x = position.x.checked_add(*amount)?;
+                position.z = position.z.checked_add(amount.checked_mul(*aim)?)?;
+            },
+            Instruction::Back(amount) => {
+                position.x = position.x.checked_sub(*amount)?;
+                position.z = position.z.checked_sub(amount.checked_mul(*aim)?)?;
+            },
+            Instruction::Up(amount) => *aim = aim.checked_sub(*amount)?,
+            Instruction::Down(amount) => *aim = aim.checked_add(*amount)?
+        }
+
+        Some(())
+    }
+}
+
+/// A submarine that starts at the surface and follows instructions under a movement model.
+pub struct Submarine<M: MovementModel> {
+    model: M,
+    position: Position,
+    state: M::State
+}
+
+impl<M: MovementModel> Submarine<M> {
+    pub fn new(model: M) -> Submarine<M> {
+        Submarine { model, position: Position::default(), state: M::State::default() }
+    }
+
+    /// Fails if the move takes the submarine further than it can keep track of, in which
+    /// case the submarine is left wherever the move got to.
+    pub fn execute(&mut self, instruction: &Instruction) -> Result<(), SolveError> {
+        self.model.step(instruction, &mut self.position, &mut self.state).ok_or_else(too_far)
+    }
+
+    pub fn run(&mut self, instructions: &[Instruction]) -> Result<(), SolveError> {
+        instructions.iter().try_for_each(|instruction| self.execute(instruction))
+    }
+
+    /// Follows a script, going through each repeat block as many times as it says.
+    pub fn follow(&mut self, commands: &[Command]) -> Result<(), SolveError> {
+        for command in commands {
+            match command {
+                Command::Move(instruction) => self.execute(instruction)?,
+                Command::Repeat(times, body) => (0..*times).try_for_each(|_| self.follow(body))?
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    pub fn state(&self) -> &M::State {
+        &self.state
+    }
+}
+
+/// Follows a script from the surface, returning where the submarine ended up.
+fn navigate<M: MovementModel>(model: M, commands: &[Command]) -> Result<Submarine<M>, SolveError> {
+    let mut submarine = Submarine::new(model);
+    submarine.follow(commands)?;
+    Ok(submarine)
+}
+
+#[derive(Default)]
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = Vec<Command>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, instructions: &Self::Input) -> Result<i64, SolveError> {
+        navigate(Direct, instructions)?.position().product()
+    }
+
+    fn part_two(&self, instructions: &Self::Input) -> Result<i64, SolveError> {
+        navigate(Aim, instructions)?.position().product()
+    }
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse_input("forward 5\nsideways 2\n").err().unwrap();
+    assert_eq!(error.to_string(), "line 2, column 1: expected forward, back, up, down or repeat, found \"sideways\"");
+
+    let error = parse_input("forward 5\ndown\n").err().unwrap();
+    assert_eq!((error.line(), error.column()), (2, 5));
+}
+
+#[test]
+fn test_script() {
+    let script = "# Out and back\n\nrepeat 2 {\n    forward 5  # cruise\n    repeat 3 {\n        down 1\n    }\n}\nback 4\n";
+    let commands = parse_input(script).unwrap();
+
+    assert_eq!(commands.len(), 2);
+    assert_eq!(navigate(Direct, &commands).unwrap().position(), Position { x: 6, z: 6 });
+    assert_eq!(navigate(Aim, &commands).unwrap().position(), Position { x: 6, z: -9 });
+
+    let error = parse_input("repeat 2 {\nforward 1\n").err().unwrap();
+    assert_eq!(error.to_string(), "line 2, column 10: expected } to close a repeat, found end of input");
+
+    let error = parse_input("forward 1\n}\n").err().unwrap();
+    assert_eq!((error.line(), error.column()), (2, 1));
+
+    let error = parse_input("repeat 2 {\nforward 1 up\n}").err().unwrap();
+    assert_eq!(error.to_string(), "line 2, column 11: expected the end of the line, found \"up\"");
+    assert!(parse_input(&"repeat 1 {\n".repeat(MAX_NESTING + 1)).is_err());
+}
+
+#[test]
+fn test_movement_models() {
+    let instructions = parse_input(Day2::EXAMPLES[0]).unwrap();
+
+    assert_eq!(navigate(Direct, &instructions).unwrap().position(), Position { x: 15, z: 10 });
+
+    let submarine = navigate(Aim, &instructions).unwrap();
+    assert_eq!((submarine.position(), *submarine.state()), (Position { x: 15, z: 60 }, 10));
+}
+
+#[test]
+fn test_limits() {
+    // Well past what an i32 holds, but still fine in an i64
+    let commands = parse_input("repeat 3 {\n forward 1000000000\n}\ndown 2").unwrap();
+    assert_eq!(Day2.part_one(&commands), Ok(6_000_000_000));
+
+    let commands = parse_input("repeat 10 {\n forward 1000000000000000000\n}").unwrap();
+    assert_eq!(Day2.part_one(&commands), Err(too_far()));
+
+    // The position still fits after every move, but the answer does not
+    let commands = parse_input("down 2000000000\nrepeat 3 {\n forward 1000000000\n}").unwrap();
+    assert_eq!(Day2.part_one(&commands), Ok(6_000_000_000_000_000_000));
+    assert_eq!(Day2.part_two(&commands), Err(too_far()));
+
+    let error = parse_input("repeat 1000 {\n repeat 1000 {\n  forward 1\n  repeat 10 {\n   up 1\n  }\n }\n}").err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 1: expected no more than 10000000 instructions once repeats are unrolled, found \"repeat\"");
+
+    let error = parse_input("repeat 100000000000 {\n}").err().unwrap();
+    assert_eq!((error.line(), error.column()), (1, 1));
+    assert!(parse_input("repeat 1000 {\n  # nothing to do\n}").is_ok());
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,209 @@
+use aoc_common::trace::NoTrace;
+use aoc_common::{grid, Frame, Grid, Param, ParseError, Part, Rng, Solution, SolveError, Text, Tracer};
+
+fn parse_decoder(line: Text) -> Result<Decoder, ParseError> {
+    if line.as_str().chars().count() != 512 {
+        return Err(line.error("512 enhancement characters"));
+    }
+
+    let mut decoder: Decoder = [false; 512];
+    for (i, c) in line.only(|c| grid::pixel(c).is_some(), "'#' or '.'")?.as_str().chars().enumerate() {
+        decoder[i] = c == '#';
+    }
+
+    Ok(decoder)
+}
+
+fn parse_input(input: &str) -> Result<(Decoder, Image), ParseError> {
+    let text = Text::new(input);
+    let mut sections = text.sections();
+
+    let decoder_lines = sections
+        .next()
+        .ok_or_else(|| text.error_at_end("an image enhancement algorithm"))?;
+
+    let mut lines = decoder_lines.lines();
+    let decoder = parse_decoder(lines.next().unwrap_or(decoder_lines))?;
+
+    if let Some(line) = lines.next() {
+        return Err(line.error("a blank line"));
+    }
+
+    let pixels: Grid<bool> = sections
+        .next()
+        .ok_or_else(|| text.error_at_end("a blank line and an image"))?
+        .grid("'#' or '.'", grid::pixel)?;
+
+    if let Some(line) = sections.next().and_then(|section| section.lines().next()) {
+        return Err(line.error("the end of the image"));
+    }
+
+    Ok((decoder, Image { pixels, background: false }))
+}
+
+/// A finite window of lit pixels, surrounded by an infinite background that is either all lit
+/// or all dark.
+#[derive(Clone)]
+pub struct Image {
+    pixels: Grid<bool>,
+    background: bool
+}
+
+impl Image {
+    fn pixel(&self, x: isize, y: isize) -> bool {
+        match (usize::try_from(x), usize::try_from(y)) {
+            (Ok(x), Ok(y)) => self.pixels.get((x, y)).copied().unwrap_or(self.background),
+            _ => self.background
+        }
+    }
+
+    fn lit(&self) -> usize {
+        self.pixels.iter().filter(|&&lit| lit).count()
+    }
+
+    fn render(&self) -> String {
+        self.pixels.render(|&lit| if lit { '#' } else { '.' })
+    }
+}
+
+type Decoder = [bool; 512];
+
+fn process(image: Image, decoder: &Decoder) -> Image {
+    // Only pixels next to the window can differ from the new background, so it grows by one
+    // on every side. Pixel (x, y) of the new window is (x - 1, y - 1) of the old one.
+    let pixels = Grid::from_fn(image.pixels.width() + 2, image.pixels.height() + 2, |x, y| {
+        let (x, y) = (x as isize - 1, y as isize - 1);
+
+        let index = (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .map(|(dx, dy)| image.pixel(x + dx, y + dy))
+            .fold(0, |acc, found| (acc * 2) + (if found { 1 } else { 0 }));
+
+        decoder[index]
+    });
+
+    let background = match image.background {
+        true => decoder[511],
+        false => decoder[0]
+    };
+
+    Image { pixels, background }
+}
+
+/// The number of lit pixels after enhancing, unless the background ends up lit too.
+fn enhance(mut image: Image, decoder: &Decoder, steps: usize, tracer: &mut dyn Tracer) -> Option<usize> {
+    tracer.emit(|| Frame::new(format!("Input image: {} lit", image.lit()), image.render()));
+
+    for number in 1..=steps {
+        image = process(image, decoder);
+        tracer.emit(|| Frame::new(format!("Step {}: {} lit", number, image.lit()), image.render()));
+    }
+
+    match image.background {
+        true => None,
+        false => Some(image.lit())
+    }
+}
+
+/// Makes up an enhancement algorithm and a square image of about `10000 * scale` pixels.
+fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut pixel = || if rng.one_in(2) { '#' } else { '.' };
+    let mut decoder: Vec<char> = (0..512).map(|_| pixel()).collect();
+
+    // If a dark neighbourhood lights up, a lit one has to go dark or the endless background
+    // stays lit and there are infinitely many lit pixels
+    if decoder[0] == '#' { decoder[511] = '.'; }
+
+    let side = (100.0 * (scale as f64).sqrt()).round() as usize;
+    let mut output: String = decoder.into_iter().collect();
+    output.push_str("\n\n");
+
+    for _ in 0..side {
+        output.extend((0..side).map(|_| pixel()));
+        output.push('\n');
+    }
+
+    output
+}
+
+fn infinitely_many_lit() -> SolveError {
+    SolveError::no_answer("the background ends up lit, so infinitely many pixels are lit")
+}
+
+pub struct Day20 {
+    /// Times to enhance the image in each part.
+    pub part_one_steps: usize,
+    pub part_two_steps: usize
+}
+
+impl Default for Day20 {
+    fn default() -> Day20 {
+        Day20 { part_one_steps: 2, part_two_steps: 50 }
+    }
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = (Decoder, Image);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<usize, SolveError> {
+        let (decoder, image) = input;
+        enhance(image.clone(), decoder, self.part_one_steps, &mut NoTrace).ok_or_else(infinitely_many_lit)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<usize, SolveError> {
+        let (decoder, image) = input;
+        enhance(image.clone(), decoder, self.part_two_steps, &mut NoTrace).ok_or_else(infinitely_many_lit)
+    }
+
+    fn trace(&self, input: &Self::Input, part: Part, tracer: &mut dyn Tracer) -> bool {
+        let (decoder, image) = input;
+
+        match part {
+            Part::One => enhance(image.clone(), decoder, self.part_one_steps, tracer),
+            Part::Two => enhance(image.clone(), decoder, self.part_two_steps, tracer)
+        };
+
+        true
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generate(rng, scale))
+    }
+
+    fn params(&mut self) -> Vec<Param<'_>> {
+        vec!(
+            Param::new("part-one-steps", &mut self.part_one_steps),
+            Param::new("part-two-steps", &mut self.part_two_steps)
+        )
+    }
+}
+
+#[test]
+fn test_generate() {
+    let (decoder, image) = parse_input(&generate(&mut Rng::new(20), 4)).unwrap();
+
+    assert_eq!((image.pixels.width(), image.pixels.height()), (200, 200));
+    assert!(!(decoder[0] && decoder[511]));
+}
+
+#[test]
+fn test_lit_background() {
+    // A dark neighbourhood lights up and a lit one goes dark, so the background flashes
+    let decoder = format!("#{}.", ".".repeat(510));
+    let (decoder, image) = parse_input(&format!("{}\n\n#..\n...\n...", decoder)).unwrap();
+
+    assert_eq!(enhance(image.clone(), &decoder, 1, &mut NoTrace), None);
+    assert!(enhance(image, &decoder, 2, &mut NoTrace).is_some());
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,238 @@
+use aoc_common::count::BigUint;
+use aoc_common::{Count, Integer, Overflow, Param, ParseError, Solution, SolveError, Text};
+use std::collections::HashMap;
+
+fn parse_position(text: Text, line: Option<Text>, player: usize) -> Result<usize, ParseError> {
+    let prefix = format!("Player {} starting position: ", player);
+    let position = line
+        .ok_or_else(|| text.error_at_end(prefix.trim_end()))?
+        .strip_prefix(&prefix, prefix.trim_end())?;
+
+    match position.parse("a position from 1 to 10")? {
+        position @ 1..=10 => Ok(position),
+        _ => Err(position.error("a position from 1 to 10"))
+    }
+}
+
+fn parse_input(input: &str) -> Result<(usize, usize), ParseError> {
+    let text = Text::new(input);
+    let mut lines = text.lines();
+    let player_one = parse_position(text, lines.next(), 1)?;
+    let player_two = parse_position(text, lines.next(), 2)?;
+
+    Ok((player_one, player_two))
+}
+
+struct DeterministicDieState {
+    roll: usize
+}
+
+impl Iterator for DeterministicDieState {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.roll += 1;
+        if self.roll > 100 {
+            self.roll = 1;
+        }
+
+        Some(self.roll)
+    }
+}
+
+// Represent 1-10 as 0-9 for easy math
+fn move_player(player_location: usize, amount: usize) -> usize {
+    match (player_location + amount) % 10 {
+        0 => 10,
+        i => i
+    }
+}
+
+fn part_one(mut player_one_location: usize, mut player_two_location: usize, target: usize) -> usize {
+    let mut die = DeterministicDieState { roll: 0 };
+    let mut roll_count = 0;
+    let mut player_one_points = 0;
+    let mut player_two_points = 0;
+    let mut current_player = 0;
+
+    while player_one_points < target && player_two_points < target {
+        let roll = die.next().unwrap() + die.next().unwrap() + die.next().unwrap();
+
+        match current_player {
+            0 => {
+                player_one_location = move_player(player_one_location, roll);
+                player_one_points += player_one_location;
+            },
+            _ => {
+                player_two_location = move_player(player_two_location, roll);
+                player_two_points += player_two_location;
+            }
+        }
+
+        roll_count += 3;
+        current_player = (current_player + 1) % 2;
+    }
+
+    let losing_points = if player_one_points > player_two_points { player_two_points } else { player_one_points };
+    losing_points * roll_count
+}
+
+#[derive(PartialEq, Eq, Hash, Debug)]
+struct WorldState {
+    player_one_location: usize,
+    player_two_location: usize,
+    player_one_points: usize,
+    player_two_points: usize,
+    player_one_turn: bool
+}
+
+#[allow(clippy::enum_variant_names)]
+enum AdvanceResult {
+    PlayerOneWin,
+    PlayerTwoWin,
+    NoWin(WorldState)
+}
+
+impl WorldState {
+    fn advance(&self, distance: usize, target: usize) -> AdvanceResult {
+        match self.player_one_turn {
+            true => {
+                let player_one_location = move_player(self.player_one_location, distance);
+                let player_one_points = player_one_location + self.player_one_points;
+
+                if self.player_one_points >= target { return AdvanceResult::PlayerOneWin; }
+
+                AdvanceResult::NoWin(WorldState {
+                    player_one_location,
+                    player_one_points,
+                    player_one_turn: false,
+                    player_two_location: self.player_two_location,
+                    player_two_points: self.player_two_points
+                })
+            },
+            false => {
+                let player_two_location = move_player(self.player_two_location, distance);
+                let player_two_points = player_two_location + self.player_two_points;
+
+                if self.player_two_points >= target { return AdvanceResult::PlayerTwoWin; }
+
+                AdvanceResult::NoWin(WorldState {
+                    player_two_location,
+                    player_two_points,
+                    player_one_turn: true,
+                    player_one_location: self.player_one_location,
+                    player_one_points: self.player_one_points
+                })
+            }
+        }
+    }
+}
+
+fn wins_for<N: Count>(
+    world: WorldState,
+    target: usize,
+    stored_wins: &mut HashMap<WorldState, (N, N)>
+) -> Result<(N, N), Overflow> {
+    if let Some(result) = stored_wins.get(&world) {
+        return Ok(result.clone());
+    }
+
+    let universe_dies = [
+        (1 + 1 + 1), (1 + 1 + 2), (1 + 1 + 3),
+        (1 + 2 + 1), (1 + 2 + 2), (1 + 2 + 3),
+        (1 + 3 + 1), (1 + 3 + 2), (1 + 3 + 3),
+        (2 + 1 + 1), (2 + 1 + 2), (2 + 1 + 3),
+        (2 + 2 + 1), (2 + 2 + 2), (2 + 2 + 3),
+        (2 + 3 + 1), (2 + 3 + 2), (2 + 3 + 3),
+        (3 + 1 + 1), (3 + 1 + 2), (3 + 1 + 3),
+        (3 + 2 + 1), (3 + 2 + 2), (3 + 2 + 3),
+        (3 + 3 + 1), (3 + 3 + 2), (3 + 3 + 3),
+    ];
+
+    let one = N::from_u64(1);
+    let result = universe_dies.iter().try_fold((N::zero(), N::zero()), |(p1_wins, p2_wins), &roll|
+        match world.advance(roll, target) {
+            AdvanceResult::PlayerOneWin => Ok((p1_wins.add(&one)?, p2_wins)),
+            AdvanceResult::PlayerTwoWin => Ok((p1_wins, p2_wins.add(&one)?)),
+            AdvanceResult::NoWin(new_world) => {
+                let (p1, p2) = wins_for(new_world, target, stored_wins)?;
+                Ok((p1_wins.add(&p1)?, p2_wins.add(&p2)?))
+            }
+        }
+    )?;
+
+    stored_wins.insert(world, result.clone());
+
+    Ok(result)
+}
+
+fn part_two<N: Count>(player_one_location: usize, player_two_location: usize, target: usize) -> Result<N, Overflow> {
+    let mut stored_wins = HashMap::new();
+    let initial_state = WorldState {
+        player_one_location,
+        player_two_location,
+        player_one_points: 0,
+        player_two_points: 0,
+        player_one_turn: true
+    };
+
+    let (p1, p2) = wins_for::<N>(initial_state, target, &mut stored_wins)?;
+
+    Ok(if p1 > p2 { p1.div(27 * 27) } else { p2.div(27 * 27) })
+}
+
+pub struct Day21 {
+    /// The score a player needs to win in each part.
+    pub part_one_target: usize,
+    pub part_two_target: usize,
+    /// What to count universes with in part two, which multiply with every turn played.
+    pub integer: Integer
+}
+
+impl Default for Day21 {
+    fn default() -> Day21 {
+        Day21 { part_one_target: 1000, part_two_target: 21, integer: Integer::U128 }
+    }
+}
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = (usize, usize);
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, positions: &Self::Input) -> Result<usize, SolveError> {
+        let &(player_one, player_two) = positions;
+        Ok(part_one(player_one, player_two, self.part_one_target))
+    }
+
+    fn part_two(&self, positions: &Self::Input) -> Result<String, SolveError> {
+        let &(player_one, player_two) = positions;
+        let target = self.part_two_target;
+
+        let count = match self.integer {
+            Integer::U64 => part_two::<u64>(player_one, player_two, target)?.to_string(),
+            Integer::U128 => part_two::<u128>(player_one, player_two, target)?.to_string(),
+            Integer::Big => part_two::<BigUint>(player_one, player_two, target)?.to_string()
+        };
+
+        Ok(count)
+    }
+
+    fn params(&mut self) -> Vec<Param<'_>> {
+        vec!(
+            Param::new("part-one-target", &mut self.part_one_target),
+            Param::new("part-two-target", &mut self.part_two_target),
+            Param::new("integer", &mut self.integer)
+        )
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,111 @@
+use aoc_common::{ParseError, Solution, SolveError, Text};
+
+/// The widest numbers the report can hold, so that multiplying two of them fits in a u32.
+const MAX_WIDTH: usize = 16;
+
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    let text = Text::new(input);
+    let width = input.lines().next().map(str::len).unwrap_or(0);
+    if width == 0 { return Err(text.error_at_end("a binary number")); }
+    if width > MAX_WIDTH {
+        return Err(text.lines().next().unwrap().error(format!("a binary number of at most {} bits", MAX_WIDTH)));
+    }
+
+    text.lines_of(|line| {
+        let line = line.only(|c| c == '0' || c == '1', "a binary digit")?;
+
+        match line.as_str().len() == width {
+            true => Ok(line.as_str().to_string()),
+            false => Err(line.error(format!("a {}-bit number", width)))
+        }
+    })
+}
+
+fn get_most_popular<'a, I: ExactSizeIterator<Item = &'a String>>(numbers: I, i: usize) -> u32 {
+    let count = numbers.fold(0, |acc, n| {
+        if n.chars().nth(i).unwrap() == '1' { acc + 1 } else { acc - 1 }
+    });
+    
+    if count < 0 { 0 } else { 1 }
+}
+
+fn part_one(numbers: &[String]) -> u32 {
+    let length = numbers[0].len();
+    
+    let (normal, flipped) = (0..length)
+        .map(|i| get_most_popular(numbers.iter(), i))
+        .fold((0, 0), |(acc_norm, acc_flip), n| ((acc_norm * 2) + n, (acc_flip * 2) + 1 - n));
+
+    normal * flipped
+}
+
+fn part_two(numbers: &[String]) -> Option<u32> {
+    let oxygen_generator_rating = iterate_bit_criteria(numbers, true, 0)?;
+    let co2_scrubber_rating = iterate_bit_criteria(numbers, false, 0)?;
+
+    Some(oxygen_generator_rating * co2_scrubber_rating)
+}
+
+/// Filters the numbers down to one, or gives None if they run out or duplicates are left
+/// once every bit has been looked at.
+fn iterate_bit_criteria(numbers: &[String], most_popular: bool, i: usize) -> Option<u32> {
+    if numbers.len() == 1 {
+        return numbers.first().unwrap().chars()
+            .map(|c| if c == '1' { 1 } else { 0 })
+            .reduce(|acc, n| (acc * 2) + n);
+    }
+
+    if numbers.is_empty() || i == numbers[0].len() {
+        return None;
+    }
+    
+    let popular_number = get_most_popular(numbers.iter(), i);
+    let criteria = if most_popular { popular_number } else { 1 - popular_number };
+    
+    let filtered: Vec<String> = numbers
+        .iter()
+        .filter(|&n| n.chars().nth(i).unwrap().to_digit(10).unwrap() == criteria)
+        .cloned()
+        .collect();
+        
+    iterate_bit_criteria(&filtered, most_popular, i + 1)
+}
+
+#[derive(Default)]
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = Vec<String>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, numbers: &Self::Input) -> Result<u32, SolveError> {
+        Ok(part_one(numbers))
+    }
+
+    fn part_two(&self, numbers: &Self::Input) -> Result<u32, SolveError> {
+        part_two(numbers).ok_or_else(|| SolveError::no_answer("the bit criteria do not narrow the numbers down to one"))
+    }
+}
+
+#[test]
+fn test_duplicate_numbers() {
+    assert_eq!(part_two(&["101".to_string(), "101".to_string()]), None);
+    assert_eq!(part_two(&["110".to_string(), "011".to_string(), "011".to_string()]), None);
+}
+
+#[test]
+fn test_parse_error() {
+    let error = Day3.parse("10110011100011110\n").err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 1: expected a binary number of at most 16 bits, found \"10110011100011110\"");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
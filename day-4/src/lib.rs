@@ -0,0 +1,198 @@
+use aoc_common::parse::integer;
+use aoc_common::{ParseError, Rng, Solution, SolveError, Text};
+
+#[derive(Clone, Debug)]
+pub struct BingoBoard {
+    numbers: Vec<u32>,
+    marked: Vec<usize>
+}
+
+impl BingoBoard {
+    fn is_finished(&self) -> bool {
+        (0..5).any(|i| {
+            let row_filled = [5 * i, 5 * i + 1, 5 * i + 2, 5 * i + 3, 5 * i + 4]
+                .iter()
+                .all(|n| self.marked.contains(n));
+
+            let col_filled = [i, i + 5, i + 10, i + 15, i + 20]
+                .iter()
+                .all(|n| self.marked.contains(n));
+
+            row_filled | col_filled
+        })
+    }
+
+    fn mark(&mut self, n: u32) {
+        if let Some(pos) = self.numbers.iter().position(|&i| i == n) {
+            self.marked.push(pos);
+        }
+    }
+
+    fn score(&self) -> u32 {
+        (0..25)
+            .filter(|i| !self.marked.contains(i))
+            .map(|i| self.numbers[i])
+            .sum()
+    }
+}
+
+fn parse_board(board: Text) -> Result<BingoBoard, ParseError> {
+    let rows: Vec<Text> = board.lines().collect();
+
+    match rows.len() {
+        0..=4 => return Err(board.error_at_end("a bingo board row")),
+        5 => (),
+        _ => return Err(rows[5].error("a blank line"))
+    }
+
+    let mut numbers = Vec::new();
+
+    for row in rows {
+        let cells: Vec<Text> = row.split_whitespace().collect();
+        if cells.len() != 5 { return Err(row.error("a row of five numbers")); }
+
+        for n in cells { numbers.push(n.parse("a bingo number")?); }
+    }
+
+    Ok(BingoBoard { numbers, marked: Vec::new() })
+}
+
+fn parse_input(input: &str) -> Result<(Vec<u32>, Vec<BingoBoard>), ParseError> {
+    let text = Text::new(input);
+    let mut sections = text.sections();
+
+    let numbers = sections
+        .next()
+        .ok_or_else(|| text.error_at_end("the drawn numbers"))?
+        .list(",", integer("a drawn number"))?;
+
+    let bingos = sections.map(parse_board).collect::<Result<_, _>>()?;
+
+    Ok((numbers, bingos))
+}
+
+fn run_part_one(numbers: &[u32], bingos: &mut [BingoBoard]) -> Option<u32> {
+    for &n in numbers.iter() {
+        bingos.iter_mut().for_each(|b| b.mark(n));
+
+        if let Some(board) = bingos.iter().find(|b| b.is_finished()) {
+            return Some(n * board.score());
+        }
+    }
+
+    None
+}
+
+fn run_part_two(numbers: &[u32], bingos: &mut [BingoBoard]) -> Option<u32> {
+    let mut unfinished: Vec<usize> = (0..bingos.len()).collect();
+
+    for &n in numbers.iter() {
+        bingos.iter_mut().for_each(|b| b.mark(n));
+
+        // The last few boards can all win on the same number, in which case the first counts
+        let still_playing: Vec<usize> = unfinished.iter().copied().filter(|&i| !bingos[i].is_finished()).collect();
+
+        if still_playing.is_empty() {
+            return unfinished.first().map(|&i| bingos[i].score() * n);
+        }
+
+        unfinished = still_playing;
+    }
+
+    None
+}
+
+/// Makes up `100 * scale` boards, then draws every number they use so all of them win.
+fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut drawn: Vec<usize> = (0..100).collect();
+    rng.shuffle(&mut drawn);
+
+    let mut output = drawn.iter().map(|n| n.to_string()).collect::<Vec<String>>().join(",");
+    output.push('\n');
+
+    for _ in 0..100 * scale {
+        let mut numbers: Vec<usize> = (0..100).collect();
+        rng.shuffle(&mut numbers);
+        output.push('\n');
+
+        for row in numbers[..25].chunks(5) {
+            output += &row.iter().map(|n| format!("{:>2}", n)).collect::<Vec<String>>().join(" ");
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+#[derive(Default)]
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = (Vec<u32>, Vec<BingoBoard>);
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<u32, SolveError> {
+        let (numbers, bingos) = input;
+        run_part_one(numbers, &mut bingos.clone()).ok_or_else(|| SolveError::no_answer("no bingo board ever wins"))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<u32, SolveError> {
+        let (numbers, bingos) = input;
+        run_part_two(numbers, &mut bingos.clone()).ok_or_else(|| SolveError::no_answer("not every bingo board wins"))
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generate(rng, scale))
+    }
+}
+
+#[test]
+fn test_generate() {
+    let input = generate(&mut Rng::new(0), 3);
+    let (numbers, boards) = parse_input(&input).unwrap();
+
+    assert_eq!((numbers.len(), boards.len()), (100, 300));
+    assert_eq!(input, generate(&mut Rng::new(0), 3));
+    assert!(run_part_two(&numbers, &mut boards.clone()).is_some());
+}
+
+#[test]
+fn test_simultaneous_wins() {
+    // The third board wins first, then the other two both win on the last number
+    let input = [
+        "1,2,3,4,6,7,8,9,5",
+        "",
+        " 1  2  3  4  5",
+        "10 11 12 13 14",
+        "15 16 17 18 19",
+        "20 21 22 23 24",
+        "25 26 27 28 29",
+        "",
+        " 6  7  8  9  5",
+        "30 31 32 33 34",
+        "35 36 37 38 39",
+        "40 41 42 43 44",
+        "45 46 47 48 49",
+        "",
+        " 1  2  3  4  6",
+        "50 51 52 53 54",
+        "55 56 57 58 59",
+        "60 61 62 63 64",
+        "65 66 67 68 69"
+    ].join("\n");
+    let (numbers, boards) = parse_input(&input).unwrap();
+
+    assert_eq!(run_part_one(&numbers, &mut boards.clone()), Some(1190 * 6));
+    assert_eq!(run_part_two(&numbers, &mut boards.clone()), Some(390 * 5));
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
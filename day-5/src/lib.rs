@@ -0,0 +1,191 @@
+use aoc_common::parse::integer;
+use aoc_common::{ParseError, Rng, Solution, SolveError, Text};
+
+#[derive(Debug)]
+pub struct Line {
+    x1: u32,
+    y1: u32,
+    x2: u32,
+    y2: u32
+}
+
+impl Line {
+    fn horizontal(&self) -> bool {
+        !((self.x1 == self.x2) || (self.y1 == self.y2))
+    }
+
+    fn contains(&self, x: u32, y: u32) -> bool {
+        match self.horizontal() {
+            true => self.contains_horizontal(x, y),
+            false => self.contains_vert_hor(x, y)
+        }
+    }
+
+    fn contains_horizontal(&self, x: u32, y: u32) -> bool {
+        let left = if self.x1 < self.x2 { (self.x1, self.y1) } else { (self.x2, self.y2) };
+        let right = if self.x1 < self.x2 { (self.x2, self.y2) } else { (self.x1, self.y1) };
+
+        if x < left.0 || x > right.0 { return false; }
+
+        let x_diff = x - left.0;
+        let y_diff: i32 = y as i32 - left.1 as i32;
+        let y_mult: i32 = if left.1 < right.1 { 1 } else { -1 };
+
+        x_diff as i32 == (y_diff * y_mult)
+    }
+
+    fn contains_vert_hor(&self, x: u32, y: u32) -> bool {
+        let lower_x = *[self.x1, self.x2].iter().min().unwrap();
+        let upper_x = *[self.x1, self.x2].iter().max().unwrap();
+        let lower_y = *[self.y1, self.y2].iter().min().unwrap();
+        let upper_y = *[self.y1, self.y2].iter().max().unwrap();
+
+        lower_x <= x && upper_x >= x && lower_y <= y && upper_y >= y
+    }
+}
+
+fn find_puzzle_bounds(lines: &[Line]) -> (u32, u32, u32, u32) {
+    let mut x_min = 1000;
+    let mut y_min = 1000;
+    let mut x_max = 0;
+    let mut y_max = 0;
+
+    for line in lines {
+        x_min = *[x_min, line.x1, line.x2].iter().min().unwrap();
+        y_min = *[y_min, line.y1, line.y2].iter().min().unwrap();
+        x_max = *[x_max, line.x1, line.x2].iter().max().unwrap();
+        y_max = *[y_max, line.y1, line.y2].iter().max().unwrap();
+    }
+
+    (x_min, y_min, x_max, y_max)
+}
+
+/// The largest coordinate on the map, which is 1000 by 1000 like the puzzle's so counting
+/// the vents over it stays quick.
+const MAX_COORDINATE: u32 = 999;
+
+fn parse_coordinate(axis: &'static str) -> impl Fn(Text) -> Result<u32, ParseError> {
+    move |coordinate| match integer(&format!("{} coordinate", axis))(coordinate)? {
+        value @ 0..=MAX_COORDINATE => Ok(value),
+        _ => Err(coordinate.error(format!("{} coordinate from 0 to {}", axis, MAX_COORDINATE)))
+    }
+}
+
+fn parse_point(point: Text) -> Result<(u32, u32), ParseError> {
+    point.key_value(",", "a point like 0,9", parse_coordinate("an x"), parse_coordinate("a y"))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Line>, ParseError> {
+    Text::new(input).lines_of(|line| {
+        let ((x1, y1), (x2, y2)) = line.key_value(" -> ", "two points separated by ' -> '", parse_point, parse_point)?;
+
+        match x1 == x2 || y1 == y2 || x1.abs_diff(x2) == y1.abs_diff(y2) {
+            true => Ok(Line { x1, y1, x2, y2 }),
+            false => Err(line.error("a horizontal, vertical or 45 degree diagonal line"))
+        }
+    })
+}
+
+fn run_part_one(lines: &[Line], bounds: (u32, u32, u32, u32)) -> u32 {
+    count_vents(lines, bounds, false)
+}
+
+fn run_part_two(lines: &[Line], bounds: (u32, u32, u32, u32)) -> u32 {
+    count_vents(lines, bounds, true)
+}
+
+fn count_vents(lines: &[Line], (x_min, y_min, x_max, y_max): (u32, u32, u32, u32), process_all: bool) -> u32 {
+    (y_min..=y_max)
+        .map(|y|
+            (x_min..=x_max)
+                .filter(|&x|
+                    lines
+                        .iter()
+                        .filter(|line| (process_all || !line.horizontal()) && line.contains(x, y))
+                        .count() > 1
+                )
+                .count() as u32
+        )
+        .sum()
+}
+
+/// Makes up `500 * scale` horizontal, vertical and diagonal vent lines on a 1000 by 1000 map.
+fn generate(rng: &mut Rng, scale: usize) -> String {
+    let directions = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+    let room = |position: usize, step: isize| match step {
+        -1 => position,
+        1 => 999 - position,
+        _ => usize::MAX
+    };
+
+    (0..500 * scale)
+        .map(|_| {
+            let (x1, y1) = (rng.below(1000), rng.below(1000));
+
+            // Keep picking until the line has room to be at least one step long
+            let ((dx, dy), longest) = loop {
+                let (dx, dy) = *rng.pick(&directions);
+                let longest = room(x1, dx).min(room(y1, dy)).min(300);
+                if longest > 0 { break ((dx, dy), longest); }
+            };
+
+            let length = rng.between(1, longest) as isize;
+            let x2 = x1 as isize + dx * length;
+            let y2 = y1 as isize + dy * length;
+
+            format!("{},{} -> {},{}\n", x1, y1, x2, y2)
+        })
+        .collect()
+}
+
+#[derive(Default)]
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = Vec<Line>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, lines: &Self::Input) -> Result<u32, SolveError> {
+        Ok(run_part_one(lines, find_puzzle_bounds(lines)))
+    }
+
+    fn part_two(&self, lines: &Self::Input) -> Result<u32, SolveError> {
+        Ok(run_part_two(lines, find_puzzle_bounds(lines)))
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generate(rng, scale))
+    }
+}
+
+#[test]
+fn test_generate() {
+    let lines = parse_input(&generate(&mut Rng::new(5), 1)).unwrap();
+
+    assert_eq!(lines.len(), 500);
+    assert!(lines.iter().all(|line| line.x1.max(line.x2).max(line.y1).max(line.y2) < 1000));
+    assert!(lines.iter().all(|line| {
+        let (dx, dy) = (line.x1.abs_diff(line.x2), line.y1.abs_diff(line.y2));
+        (dx == 0) != (dy == 0) || dx == dy
+    }));
+}
+
+#[test]
+fn test_parse_error() {
+    let error = Day5.parse("0,9 -> 5,9\n0,0 -> 8,4").err().unwrap();
+    assert_eq!(error.to_string(), "line 2, column 1: expected a horizontal, vertical or 45 degree diagonal line, found \"0,0 -> 8,4\"");
+
+    let error = Day5.parse("0,9 -> 5,9\n0,2147483648 -> 0,0").err().unwrap();
+    assert_eq!(error.to_string(), "line 2, column 3: expected a y coordinate from 0 to 999, found \"2147483648\"");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,137 @@
+use aoc_common::parse::integer;
+use aoc_common::count::BigUint;
+use aoc_common::{Count, Integer, Overflow, Param, ParseError, Solution, SolveError, Text};
+#[cfg(test)]
+use aoc_common::Part;
+#[cfg(test)]
+use proptest::{collection::vec, prelude::*};
+
+fn iterate_n<N: Count>(fish: &[u8], n: usize) -> Result<N, Overflow> {
+    let mut counts = vec!(N::zero(); 9);
+    for f in fish.iter() { counts[*f as usize] = counts[*f as usize].add(&N::from_u64(1))?; }
+
+    // Each day the zeroes spawn new fish at 8 and start over at 6
+    for _ in 0..n {
+        counts.rotate_left(1);
+        counts[6] = counts[6].add(&counts[8])?;
+    }
+
+    counts.iter().try_fold(N::zero(), |total, count| total.add(count))
+}
+
+fn parse_timer(timer: Text) -> Result<u8, ParseError> {
+    match integer("a number")(timer)? {
+        value @ 0..=8 => Ok(value),
+        _ => Err(timer.error("a timer from 0 to 8"))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<u8>, ParseError> {
+    Text::new(input).trim().list(",", parse_timer)
+}
+
+pub struct Day6 {
+    /// Days of spawning to simulate in each part.
+    pub part_one_days: usize,
+    pub part_two_days: usize,
+    /// What to count fish with, for simulations long enough to overflow a u64.
+    pub integer: Integer
+}
+
+impl Day6 {
+    fn count(&self, fish: &[u8], days: usize) -> Result<String, SolveError> {
+        let count = match self.integer {
+            Integer::U64 => iterate_n::<u64>(fish, days)?.to_string(),
+            Integer::U128 => iterate_n::<u128>(fish, days)?.to_string(),
+            Integer::Big => iterate_n::<BigUint>(fish, days)?.to_string()
+        };
+
+        Ok(count)
+    }
+}
+
+impl Default for Day6 {
+    fn default() -> Day6 {
+        Day6 { part_one_days: 80, part_two_days: 256, integer: Integer::U64 }
+    }
+}
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = Vec<u8>;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, fish: &Self::Input) -> Result<String, SolveError> {
+        self.count(fish, self.part_one_days)
+    }
+
+    fn part_two(&self, fish: &Self::Input) -> Result<String, SolveError> {
+        self.count(fish, self.part_two_days)
+    }
+
+    fn params(&mut self) -> Vec<Param<'_>> {
+        vec!(
+            Param::new("part-one-days", &mut self.part_one_days),
+            Param::new("part-two-days", &mut self.part_two_days),
+            Param::new("integer", &mut self.integer)
+        )
+    }
+}
+
+#[cfg(test)]
+fn simulate_each_fish(fish: &[u8], n: usize) -> usize {
+    let mut fish = fish.to_vec();
+
+    for _ in 0..n {
+        let spawned = fish.iter().filter(|&&timer| timer == 0).count();
+        fish.iter_mut().for_each(|timer| *timer = if *timer == 0 { 6 } else { *timer - 1 });
+        fish.extend(std::iter::repeat_n(8, spawned));
+    }
+
+    fish.len()
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn prop_iterate_n_matches_simulation(fish in vec(0u8..=8, 0..20), n in 0usize..50) {
+        prop_assert_eq!(iterate_n::<u64>(&fish, n), Ok(simulate_each_fish(&fish, n) as u64));
+    }
+}
+
+#[test]
+fn test_params() {
+    let mut day = Day6::default();
+    let fish = day.parse(Day6::EXAMPLES[0]).unwrap();
+    day.params()[0].value.set("18").unwrap();
+
+    assert_eq!(day.part_one(&fish), Ok("26".to_string()));
+    assert!(day.params()[1].value.set("-1").is_err());
+}
+
+#[test]
+fn test_overflow() {
+    let mut day = Day6 { part_two_days: 3000, ..Day6::default() };
+    let overflowed = aoc_common::Solver::solve(&day, Day6::EXAMPLES[0], &[Part::Two]);
+    assert_eq!(overflowed.err(), Some(SolveError::Overflow(Overflow { integer: Integer::U64 }).in_part(Part::Two)));
+
+    day.integer = Integer::Big;
+    let solved = aoc_common::Solver::solve(&day, Day6::EXAMPLES[0], &[Part::Two]).unwrap();
+    assert!(solved.answers[0].value.len() > 100);
+}
+
+#[test]
+fn test_parse_error() {
+    let error = Day6::default().parse("3,9").err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 3: expected a timer from 0 to 8, found \"9\"");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,96 @@
+use aoc_common::parse::integer;
+use aoc_common::{ParseError, Solution, SolveError, Text};
+
+enum FuelCalculation {
+    Linear,
+    Triangle
+}
+
+impl FuelCalculation {
+    fn diff(&self, n1: u32, n2: u32) -> u64 {
+        let linear_diff = u64::from(n1.abs_diff(n2));
+
+        match self {
+            FuelCalculation::Linear => linear_diff,
+            FuelCalculation::Triangle => (linear_diff * (linear_diff + 1)) / 2,
+        }
+    }
+}
+
+fn alignment_fuel_cost(positions: &[u32], fuel_calculation: &FuelCalculation) -> Option<u64> {
+    let min = *positions.iter().min()?;
+    let max = *positions.iter().max()?;
+
+    (min..=max)
+        .map(|n| fuel_cost(positions, n, fuel_calculation))
+        .min()
+}
+
+fn fuel_cost(positions: &[u32], alignment: u32, fuel_calculation: &FuelCalculation) -> u64 {
+    positions
+        .iter()
+        .map(|&n| fuel_calculation.diff(n, alignment))
+        .sum()
+}
+
+/// The furthest a crab can be from 0, which keeps trying every alignment quick.
+const MAX_POSITION: u32 = 100_000;
+
+fn parse_position(position: Text) -> Result<u32, ParseError> {
+    match integer("a number")(position)? {
+        value @ 0..=MAX_POSITION => Ok(value),
+        _ => Err(position.error(format!("a position from 0 to {}", MAX_POSITION)))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<u32>, ParseError> {
+    Text::new(input).trim().list(",", parse_position)
+}
+
+#[derive(Default)]
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = Vec<u32>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, positions: &Self::Input) -> Result<u64, SolveError> {
+        alignment_fuel_cost(positions, &FuelCalculation::Linear).ok_or_else(|| SolveError::no_answer("there are no crabs"))
+    }
+
+    fn part_two(&self, positions: &Self::Input) -> Result<u64, SolveError> {
+        alignment_fuel_cost(positions, &FuelCalculation::Triangle).ok_or_else(|| SolveError::no_answer("there are no crabs"))
+    }
+}
+
+#[test]
+fn test_no_crabs() {
+    assert_eq!(alignment_fuel_cost(&[], &FuelCalculation::Linear), None);
+}
+
+#[test]
+fn test_alignment_range() {
+    // Aligning on the furthest crab can be cheapest, and is the only choice for a single crab
+    assert_eq!(alignment_fuel_cost(&[0, 10, 10], &FuelCalculation::Linear), Some(10));
+    assert_eq!(alignment_fuel_cost(&[7], &FuelCalculation::Triangle), Some(0));
+}
+
+#[test]
+fn test_distant_crabs() {
+    let positions = Day7.parse("0,100000").unwrap();
+    assert_eq!(Day7.part_two(&positions), Ok(2_500_050_000));
+
+    let error = Day7.parse("0,100001").err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 3: expected a position from 0 to 100000, found \"100001\"");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
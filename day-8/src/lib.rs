@@ -0,0 +1,216 @@
+use aoc_common::{ParseError, Solution, SolveError, Text};
+use std::collections::HashMap;
+
+type Entry = (Vec<String>, Vec<String>);
+
+fn parse_patterns(section: Text, count: usize) -> Result<Vec<String>, ParseError> {
+    let patterns = section
+        .split_whitespace()
+        .map(|pattern| pattern.only(|c| ('a'..='g').contains(&c), "segments a to g"))
+        .collect::<Result<Vec<Text>, _>>()?;
+
+    match patterns.len() == count {
+        true => Ok(patterns.iter().map(|p| p.as_str().to_string()).collect()),
+        false => Err(section.trim().error(format!("{} patterns", count)))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Entry>, ParseError> {
+    Text::new(input).lines_of(|line| {
+        let (signals, outputs) = line.split_once("|", "signals and outputs separated by '|'")?;
+
+        let entry = (parse_patterns(signals, 10)?, parse_patterns(outputs, 4)?);
+
+        match wired_up(&entry) {
+            true => Ok(entry),
+            false => Err(line.error("signals wired up to the digits 0 to 9, and outputs among them"))
+        }
+    })
+}
+
+/// The segments each digit from 0 to 9 lights up.
+const DIGITS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+
+fn sorted(segments: impl Iterator<Item = char>) -> String {
+    let mut segments: Vec<char> = segments.collect();
+    segments.sort_unstable();
+    segments.into_iter().collect()
+}
+
+/// Whether some wiring turns the signals into the ten digits, so that they can be decoded,
+/// and every output is one of the signals.
+fn wired_up((signals, outputs): &Entry) -> bool {
+    let mappings = match determine_mappings(signals) {
+        Some(mappings) => mappings,
+        None => return false
+    };
+
+    let mut wired = Vec::new();
+    for signal in signals {
+        match signal.chars().map(|c| mappings.get(&c).copied()).collect::<Option<Vec<char>>>() {
+            Some(segments) => wired.push(sorted(segments.into_iter())),
+            None => return false
+        }
+    }
+
+    wired.sort_unstable();
+    let mut digits = DIGITS.map(String::from);
+    digits.sort_unstable();
+
+    let signals: Vec<String> = signals.iter().map(|signal| sorted(signal.chars())).collect();
+    wired[..] == digits[..] && outputs.iter().all(|output| signals.contains(&sorted(output.chars())))
+}
+
+fn distinguishable_by_length(digit: &&String) -> bool {
+    matches!(digit.len(), 2 | 3 | 4 | 7)
+}
+
+fn part_one_count(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .map(|(_signals, outputs)| {
+            outputs
+                .iter()
+                .filter(distinguishable_by_length)
+                .count()
+        })
+        .sum()
+}
+
+fn decode_entry((signals, outputs): &Entry) -> usize {
+    let mappings = determine_mappings(signals).expect("parsing checks that the signals can be decoded");
+
+    outputs
+        .iter()
+        .map(|output| apply_mappings(&mappings, output))
+        .fold(0, |acc, n| acc * 10 + n)
+}
+
+fn determine_mappings(signals: &[String]) -> Option<HashMap<char, char>> {
+    let mut output = HashMap::new();
+
+    // Find 1 and 7
+    let one = signals.iter().find(|s| s.len() == 2)?;
+    let seven = signals.iter().find(|s| s.len() == 3)?;
+
+    // The diff between them is 'a'
+    let a = seven.chars().find(|&c| !one.contains(c))?;
+    output.insert(a, 'a');
+
+    // Find 6
+    let one_first = one.chars().next()?;
+    let one_second = one.chars().nth(1)?;
+    let six = signals.iter().find(|s| {
+        s.len() == 6 && (!s.contains(one_first) || !s.contains(one_second))
+    })?;
+
+    // The missing piece can find which one character is which
+    let (c, f) = match six.contains(one_first) {
+        false => (one_first, one_second),
+        true => (one_second, one_first),
+    };
+
+    output.insert(c, 'c');
+    output.insert(f, 'f');
+
+    // Find 4, mark b and d (though we don't know which is which)
+    let four = signals.iter().find(|s| s.len() == 4)?;
+
+    // Find 0, the missing piece is d
+    let zero = signals.iter().find(|s| {
+        s.len() == 6 && !four.chars().filter(|&chr| chr != c).all(|c| s.contains(c))
+    })?;
+
+    let d = "abcdefg".chars().find(|&c| !zero.contains(c))?;
+    output.insert(d, 'd');
+
+    // In 4, the unknown piece is b
+    let known_letters = [c, d, f];
+    let b = four.chars().find(|c| !known_letters.contains(c))?;
+    output.insert(b, 'b');
+
+    // Find 3, the unknown piece is g
+    let known_letters = [a, c, d, f];
+    let three = signals.iter().find(|s| {
+        s.len() == 5 && known_letters.iter().all(|&l| s.contains(l))
+    })?;
+    let g = three.chars().find(|c| !known_letters.contains(c))?;
+    output.insert(g, 'g');
+
+    // The only character left is e
+    let e = "abcdefg".chars().find(|c| !output.keys().any(|v| v == c))?;
+    output.insert(e, 'e');
+
+    Some(output)
+}
+
+fn apply_mappings(mappings: &HashMap<char, char>, output: &str) -> usize {
+    let applied: String = output.chars().map(|c| mappings[&c]).collect();
+
+    match applied.len() {
+        2 => 1,
+        3 => 7,
+        4 => 4,
+        7 => 8,
+        6 => match (applied.contains('d'), applied.contains('e')) {
+            (false, _) => 0,
+            (true, false) => 9,
+            (true, true) => 6
+        },
+        5 => match (applied.contains('b'), applied.contains('c'), applied.contains('e')) {
+            (true, false, false) => 5,
+            (true, true, false) => 9,
+            (false, true, true) => 2,
+            (false, true, false) => 3,
+            _ => panic!()
+        },
+        _ => panic!()
+    }
+}
+
+fn part_two(entries: &[Entry]) -> usize {
+    entries.iter().map(decode_entry).sum()
+}
+
+#[derive(Default)]
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = Vec<Entry>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, entries: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part_one_count(entries))
+    }
+
+    fn part_two(&self, entries: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part_two(entries))
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    // Without a pattern for 1 the wiring cannot be worked out
+    let line = "abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg | abcdefg abcdefg abcdefg abcdefg";
+    let error = Day8.parse(line).err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 1: expected signals wired up to the digits 0 to 9, and outputs among them, found \"abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg | abcdefg abcdefg abcdefg abcdefg\"");
+
+    let line = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb ab";
+    assert!(Day8.parse(line).is_ok());
+
+    // The same, but with an output no signal lights up
+    let line = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb abc";
+    let error = Day8.parse(line).err().unwrap();
+    assert_eq!(error.to_string(), "line 1, column 1: expected signals wired up to the digits 0 to 9, and outputs among them, found \"acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb abc\"");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
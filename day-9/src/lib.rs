@@ -0,0 +1,70 @@
+use aoc_common::{search, Grid, ParseError, Solution, SolveError};
+
+fn find_mins(heights: &Grid<u8>) -> Vec<(usize, usize)> {
+    heights
+        .positions()
+        .filter(|&position| {
+            heights
+                .neighbours(position)
+                .all(|neighbour| heights[neighbour] > heights[position])
+        })
+        .collect()
+}
+
+fn part_one(heights: &Grid<u8>) -> usize {
+    find_mins(heights)
+        .iter()
+        .map(|&position| heights[position] as usize + 1)
+        .sum()
+}
+
+fn traverse_basin(heights: &Grid<u8>, low: (usize, usize)) -> Vec<(usize, usize)> {
+    search::flood_fill(heights, low, |&from, &to| heights[to] > heights[from] && heights[to] < 9)
+}
+
+fn part_two(heights: &Grid<u8>) -> usize {
+    let mut basins: Vec<usize> = find_mins(heights)
+        .iter()
+        .map(|&low| traverse_basin(heights, low).len())
+        .collect();
+
+    basins.sort_by(|a, b| b.cmp(a));
+
+    basins.iter().take(3).product()
+}
+
+#[derive(Default)]
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = include_str!("../input");
+    const EXAMPLES: &'static [&'static str] = EXAMPLE_INPUTS;
+
+    type Input = Grid<u8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_digits(input)
+    }
+
+    fn part_one(&self, heights: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part_one(heights))
+    }
+
+    fn part_two(&self, heights: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part_two(heights))
+    }
+}
+
+#[test]
+fn test_non_square_grid() {
+    let input = Day9.parse("2199\n3987\n").unwrap();
+    assert_eq!(part_one(&input), 10);
+
+    let error = Day9.parse("2199\n39\n").err().unwrap();
+    assert_eq!(error.to_string(), "line 2, column 1: expected a row 4 cells wide, found \"39\"");
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
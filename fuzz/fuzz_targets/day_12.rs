@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let day = day_12::Day12;
+    if let Ok(parsed) = day.parse(input) {
+        let _ = day.part_one(&parsed);
+        let _ = day.part_two(&parsed);
+    }
+});